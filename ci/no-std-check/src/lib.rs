@@ -0,0 +1,17 @@
+//! Builds `fixed-collections` without `std` to prove it stays `no_std`.
+//!
+//! Run with `cargo build --manifest-path ci/no-std-check/Cargo.toml`.
+#![no_std]
+
+use fixed_collections::RingBuffer;
+
+pub fn sum_window(values: &[u32]) -> u32 {
+    let mut ring: RingBuffer<u32, 4> = RingBuffer::new();
+    for &value in values {
+        if ring.is_full() {
+            let _ = ring.pop_front();
+        }
+        let _ = ring.push_back(value);
+    }
+    ring.iter().sum()
+}
@@ -1,23 +1,234 @@
-use std::{error::Error, fmt::{Display}};
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::{error::Error, fmt::Debug};
 
 #[derive(Debug)]
 pub struct EmptyCollectionError;
 
 impl Display for EmptyCollectionError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "EmptyCollectionError")
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for EmptyCollectionError {}
 
+impl EmptyCollectionError {
+    /// Returns [`ErrorKind::Empty`].
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::Empty
+    }
+}
+
 #[derive(Debug)]
 pub struct FullCollectionError;
 
 impl Display for FullCollectionError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "FullCollectionError")
     }
 }
 
-impl Error for FullCollectionError {}
\ No newline at end of file
+#[cfg(feature = "std")]
+impl Error for FullCollectionError {}
+
+impl FullCollectionError {
+    /// Returns [`ErrorKind::Full`].
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::Full
+    }
+}
+
+/// Returned when pushing into a full collection, handing the rejected value
+/// back to the caller.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PushError<T>(pub T);
+
+impl<T> PushError<T> {
+    /// Consumes the error, returning the value that couldn't be pushed.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Returns [`ErrorKind::Full`].
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::Full
+    }
+}
+
+impl<T> Display for PushError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PushError")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Error for PushError<T> where T: Debug {}
+
+impl<T> From<PushError<T>> for FullCollectionError {
+    fn from(_: PushError<T>) -> Self {
+        FullCollectionError
+    }
+}
+
+/// Returned when appending more elements than fit in the remaining capacity,
+/// reporting how far the append got.
+#[derive(Debug, PartialEq, Eq)]
+pub struct AppendError {
+    /// Number of elements moved before the collection filled up.
+    pub moved: usize,
+    /// Number of elements left behind in the source collection.
+    pub remaining: usize,
+}
+
+impl Display for AppendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AppendError: moved {} elements, {} remaining", self.moved, self.remaining)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AppendError {}
+
+impl AppendError {
+    /// Returns [`ErrorKind::Full`].
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::Full
+    }
+}
+
+impl From<AppendError> for FullCollectionError {
+    fn from(_: AppendError) -> Self {
+        FullCollectionError
+    }
+}
+
+/// The broad category of an error, stable across error types so callers can
+/// bucket them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The collection had no element to give.
+    Empty,
+    /// The collection had no room left.
+    Full,
+}
+
+/// Any error the collections return, so `?` can unify them in functions that
+/// both push and pop.
+///
+/// # Examples
+/// ```
+/// use fixed_collections::{RingBuffer, error::{CollectionError, ErrorKind}};
+///
+/// fn move_front<const SIZE: usize>(from: &mut RingBuffer<u8, SIZE>, to: &mut RingBuffer<u8, SIZE>)
+///     -> Result<(), CollectionError> {
+///     let value: u8 = from.pop_front()?;
+///     to.push_back(value)?;
+///     Ok(())
+/// }
+///
+/// let mut from: RingBuffer<u8, 1> = RingBuffer::from([1]);
+/// let mut to: RingBuffer<u8, 1> = RingBuffer::new();
+/// assert!(move_front(&mut from, &mut to).is_ok());
+///
+/// let empty: CollectionError = move_front(&mut from, &mut to).unwrap_err();
+/// assert_eq!(empty.kind(), ErrorKind::Empty);
+/// assert!(matches!(empty, CollectionError::Empty(_)));
+///
+/// from.push_back(2).unwrap();
+/// let full: CollectionError = move_front(&mut from, &mut to).unwrap_err();
+/// assert_eq!(full.kind(), ErrorKind::Full);
+/// ```
+///
+/// Each concrete error converts into the matching variant:
+/// ```
+/// use fixed_collections::error::{
+///     AppendError, CollectionError, EmptyCollectionError, ErrorKind, FullCollectionError, PushError,
+/// };
+///
+/// let empty: CollectionError = EmptyCollectionError.into();
+/// assert_eq!(empty.kind(), EmptyCollectionError.kind());
+///
+/// let full: CollectionError = FullCollectionError.into();
+/// assert_eq!(full.kind(), ErrorKind::Full);
+///
+/// let push: CollectionError = PushError(1).into();
+/// assert_eq!(push.kind(), PushError(1).kind());
+///
+/// let append: CollectionError = AppendError { moved: 1, remaining: 2 }.into();
+/// assert_eq!(append.kind(), ErrorKind::Full);
+/// ```
+#[derive(Debug)]
+pub enum CollectionError {
+    /// An element was requested from an empty collection.
+    Empty(EmptyCollectionError),
+    /// An element was added to a full collection.
+    Full(FullCollectionError),
+}
+
+impl CollectionError {
+    /// Returns the category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            CollectionError::Empty(error) => error.kind(),
+            CollectionError::Full(error) => error.kind(),
+        }
+    }
+}
+
+impl Display for CollectionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CollectionError")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CollectionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CollectionError::Empty(error) => Some(error),
+            CollectionError::Full(error) => Some(error),
+        }
+    }
+}
+
+impl From<EmptyCollectionError> for CollectionError {
+    fn from(error: EmptyCollectionError) -> Self {
+        CollectionError::Empty(error)
+    }
+}
+
+impl From<FullCollectionError> for CollectionError {
+    fn from(error: FullCollectionError) -> Self {
+        CollectionError::Full(error)
+    }
+}
+
+impl<T> From<PushError<T>> for CollectionError {
+    fn from(error: PushError<T>) -> Self {
+        CollectionError::Full(error.into())
+    }
+}
+
+impl From<AppendError> for CollectionError {
+    fn from(error: AppendError) -> Self {
+        CollectionError::Full(error.into())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn collection_error_keeps_concrete_error_as_source() {
+        let empty: CollectionError = EmptyCollectionError.into();
+        assert_eq!(empty.source().unwrap().to_string(), "EmptyCollectionError");
+
+        let full: CollectionError = PushError(1).into();
+        assert_eq!(full.source().unwrap().to_string(), "FullCollectionError");
+    }
+}
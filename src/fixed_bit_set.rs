@@ -0,0 +1,388 @@
+use core::ops::{BitAnd, BitOr, BitXor};
+
+/// Returns the number of `u64` words needed to store `bits` bits, for use as
+/// the `WORDS` parameter of [`FixedBitSet`].
+///
+/// # Examples
+/// ```
+/// use fixed_collections::fixed_bit_set::words_for;
+///
+/// assert_eq!(words_for(0), 0);
+/// assert_eq!(words_for(64), 1);
+/// assert_eq!(words_for(65), 2);
+/// ```
+pub const fn words_for(bits: usize) -> usize {
+    bits.div_ceil(64)
+}
+
+/// A set of `BITS` flags packed into `u64` words, without allocating.
+///
+/// Stable Rust can't yet size an array from an expression of a const
+/// parameter, so the word count is a second parameter, checked at compile
+/// time to equal [`words_for`]`(BITS)`.
+///
+/// # Examples
+/// ```
+/// use fixed_collections::{FixedBitSet, fixed_bit_set::words_for};
+///
+/// let mut flags: FixedBitSet<100, { words_for(100) }> = FixedBitSet::new();
+/// flags.set(99);
+/// assert!(flags.get(99));
+/// ```
+///
+/// ```compile_fail
+/// use fixed_collections::FixedBitSet;
+///
+/// let flags: FixedBitSet<100, 1> = FixedBitSet::new();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FixedBitSet<const BITS: usize, const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const BITS: usize, const WORDS: usize> FixedBitSet<BITS, WORDS> {
+    /// Creates a bit set with every bit cleared.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let flags: FixedBitSet<64, 1> = FixedBitSet::new();
+    /// assert_eq!(flags.count_ones(), 0);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        const { assert!(WORDS == words_for(BITS), "WORDS must equal words_for(BITS)") };
+        Self { words: [0; WORDS] }
+    }
+
+    /// Returns the value of bit `index`.
+    ///
+    /// # Panics
+    /// Panics if `index >= BITS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut flags: FixedBitSet<16, 1> = FixedBitSet::new();
+    /// flags.set(3);
+    /// assert!(flags.get(3));
+    /// assert!(!flags.get(4));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let flags: FixedBitSet<100, 2> = FixedBitSet::new();
+    /// flags.get(100);
+    /// ```
+    pub fn get(&self, index: usize) -> bool {
+        let (word, mask): (usize, u64) = Self::locate(index);
+        self.words[word] & mask != 0
+    }
+
+    /// Sets bit `index` to `1`.
+    ///
+    /// # Panics
+    /// Panics if `index >= BITS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut flags: FixedBitSet<16, 1> = FixedBitSet::new();
+    /// flags.set(3);
+    /// assert!(flags.get(3));
+    /// ```
+    pub fn set(&mut self, index: usize) {
+        let (word, mask): (usize, u64) = Self::locate(index);
+        self.words[word] |= mask;
+    }
+
+    /// Sets bit `index` to `0`.
+    ///
+    /// # Panics
+    /// Panics if `index >= BITS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut flags: FixedBitSet<16, 1> = FixedBitSet::new();
+    /// flags.set(3);
+    /// flags.clear(3);
+    /// assert!(!flags.get(3));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut flags: FixedBitSet<128, 2> = FixedBitSet::new();
+    /// flags.clear(128);
+    /// ```
+    pub fn clear(&mut self, index: usize) {
+        let (word, mask): (usize, u64) = Self::locate(index);
+        self.words[word] &= !mask;
+    }
+
+    /// Flips bit `index`.
+    ///
+    /// # Panics
+    /// Panics if `index >= BITS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut flags: FixedBitSet<16, 1> = FixedBitSet::new();
+    /// flags.toggle(3);
+    /// assert!(flags.get(3));
+    /// flags.toggle(3);
+    /// assert!(!flags.get(3));
+    /// ```
+    pub fn toggle(&mut self, index: usize) {
+        let (word, mask): (usize, u64) = Self::locate(index);
+        self.words[word] ^= mask;
+    }
+
+    /// Returns the number of bits set to `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut flags: FixedBitSet<16, 1> = FixedBitSet::new();
+    /// flags.set(1);
+    /// flags.set(7);
+    /// assert_eq!(flags.count_ones(), 2);
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Sets every bit to `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut flags: FixedBitSet<16, 1> = FixedBitSet::new();
+    /// flags.set_all();
+    /// assert!(flags.get(15));
+    /// assert_eq!(flags.count_ones(), 16);
+    /// ```
+    pub fn set_all(&mut self) {
+        self.words = [u64::MAX; WORDS];
+        // Keep the bits past BITS cleared so count_ones stays accurate.
+        let used: usize = BITS % 64;
+        if used != 0 {
+            self.words[WORDS - 1] = (1 << used) - 1;
+        }
+    }
+
+    /// Sets every bit to `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut flags: FixedBitSet<100, 2> = FixedBitSet::new();
+    /// flags.set_all();
+    /// flags.clear_all();
+    /// assert_eq!(flags.count_ones(), 0);
+    /// ```
+    pub fn clear_all(&mut self) {
+        self.words = [0; WORDS];
+    }
+
+    /// Returns the word holding bit `index` and the mask selecting it.
+    fn locate(index: usize) -> (usize, u64) {
+        if index >= BITS {
+            panic!("bit index out of bounds: the len is {BITS} but the index is {index}");
+        }
+        (index / 64, 1 << (index % 64))
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> Default for FixedBitSet<BITS, WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> BitAnd for FixedBitSet<BITS, WORDS> {
+    type Output = Self;
+
+    /// Returns the bits set in both sets.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut a: FixedBitSet<128, 2> = FixedBitSet::new();
+    /// let mut b: FixedBitSet<128, 2> = FixedBitSet::new();
+    /// a.set(1);
+    /// a.set(64);
+    /// b.set(64);
+    /// b.set(127);
+    ///
+    /// let both = a & b;
+    /// assert_eq!(both.count_ones(), 1);
+    /// assert!(both.get(64));
+    /// ```
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        for (word, other) in self.words.iter_mut().zip(rhs.words) {
+            *word &= other;
+        }
+        self
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> BitOr for FixedBitSet<BITS, WORDS> {
+    type Output = Self;
+
+    /// Returns the bits set in either set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut a: FixedBitSet<128, 2> = FixedBitSet::new();
+    /// let mut b: FixedBitSet<128, 2> = FixedBitSet::new();
+    /// a.set(1);
+    /// a.set(64);
+    /// b.set(64);
+    /// b.set(127);
+    ///
+    /// let either = a | b;
+    /// assert_eq!(either.count_ones(), 3);
+    /// assert!(either.get(1) && either.get(64) && either.get(127));
+    /// ```
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        for (word, other) in self.words.iter_mut().zip(rhs.words) {
+            *word |= other;
+        }
+        self
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> BitXor for FixedBitSet<BITS, WORDS> {
+    type Output = Self;
+
+    /// Returns the bits set in exactly one of the sets.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedBitSet;
+    ///
+    /// let mut a: FixedBitSet<128, 2> = FixedBitSet::new();
+    /// let mut b: FixedBitSet<128, 2> = FixedBitSet::new();
+    /// a.set(1);
+    /// a.set(64);
+    /// b.set(64);
+    /// b.set(127);
+    ///
+    /// let one = a ^ b;
+    /// assert_eq!(one.count_ones(), 2);
+    /// assert!(one.get(1) && !one.get(64) && one.get(127));
+    /// ```
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        for (word, other) in self.words.iter_mut().zip(rhs.words) {
+            *word ^= other;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits_on_either_side_of_a_word_boundary_are_independent() {
+        let mut flags: FixedBitSet<128, 2> = FixedBitSet::new();
+        flags.set(63);
+        assert!(flags.get(63));
+        assert!(!flags.get(64));
+        assert_eq!(flags.words, [1 << 63, 0]);
+
+        flags.set(64);
+        flags.clear(63);
+        assert!(!flags.get(63));
+        assert!(flags.get(64));
+        assert_eq!(flags.words, [0, 1]);
+
+        flags.toggle(63);
+        flags.toggle(64);
+        assert!(flags.get(63));
+        assert!(!flags.get(64));
+        assert_eq!(flags.words, [1 << 63, 0]);
+    }
+
+    #[test]
+    fn last_bit_of_a_partial_word_is_reachable() {
+        let mut flags: FixedBitSet<130, 3> = FixedBitSet::new();
+        flags.set(129);
+        assert!(flags.get(129));
+        assert!(!flags.get(128));
+        assert_eq!(flags.words, [0, 0, 1 << 1]);
+    }
+
+    #[test]
+    fn count_ones_spans_every_word() {
+        let mut flags: FixedBitSet<130, 3> = FixedBitSet::new();
+        flags.set(0);
+        flags.set(63);
+        flags.set(64);
+        flags.set(129);
+        flags.toggle(63);
+        flags.toggle(100);
+        flags.clear(0);
+        assert_eq!(flags.count_ones(), 3);
+
+        // Setting a bit twice counts it once.
+        flags.set(64);
+        assert_eq!(flags.count_ones(), 3);
+    }
+
+    /// Checks that `set_all` sets exactly `BITS` bits and nothing past them.
+    fn set_all_sets_exactly_bits<const BITS: usize, const WORDS: usize>() {
+        let mut flags: FixedBitSet<BITS, WORDS> = FixedBitSet::new();
+        flags.set_all();
+        assert_eq!(flags.count_ones(), BITS, "BITS = {BITS}");
+        assert!((0..BITS).all(|index| flags.get(index)));
+
+        // Clearing a bit shows the padding wasn't counted in its place.
+        if BITS != 0 {
+            flags.clear(BITS - 1);
+            assert_eq!(flags.count_ones(), BITS - 1, "BITS = {BITS}");
+        }
+    }
+
+    #[test]
+    fn set_all_leaves_the_tail_of_the_last_word_clear() {
+        set_all_sets_exactly_bits::<1, 1>();
+        set_all_sets_exactly_bits::<63, 1>();
+        set_all_sets_exactly_bits::<65, 2>();
+        set_all_sets_exactly_bits::<100, 2>();
+        set_all_sets_exactly_bits::<130, 3>();
+    }
+
+    #[test]
+    fn set_all_fills_whole_words() {
+        set_all_sets_exactly_bits::<0, 0>();
+        set_all_sets_exactly_bits::<64, 1>();
+        set_all_sets_exactly_bits::<128, 2>();
+    }
+
+    #[test]
+    fn operators_ignore_the_unused_tail() {
+        let mut a: FixedBitSet<100, 2> = FixedBitSet::new();
+        let b: FixedBitSet<100, 2> = FixedBitSet::new();
+        a.set_all();
+        assert_eq!((a ^ b).count_ones(), 100);
+        assert_eq!((a | b).count_ones(), 100);
+        assert_eq!((a & a).count_ones(), 100);
+    }
+}
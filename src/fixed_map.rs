@@ -0,0 +1,434 @@
+use core::{borrow::Borrow, fmt::Debug, hash::{Hash, Hasher}, mem};
+
+use crate::{error::PushError};
+
+/// A hash map holding up to `SIZE` entries without allocating.
+///
+/// Entries live in a fixed array of slots using open addressing with linear
+/// probing. Removed entries leave a tombstone behind so that lookups keep
+/// probing past them.
+pub struct FixedMap<K, V, const SIZE: usize> where K: Eq + Hash {
+    len: usize,
+    slots: [Slot<K, V>; SIZE],
+}
+
+enum Slot<K, V> {
+    Empty,
+    Tombstone,
+    Occupied(K, V),
+}
+
+/// Outcome of probing for a key.
+enum Probe {
+    /// The key lives at this slot.
+    Found(usize),
+    /// The key is absent, this is the first slot it could be inserted at.
+    Vacant(usize),
+    /// The key is absent and there's no room for it.
+    Full,
+}
+
+impl<K, V, const SIZE: usize> FixedMap<K, V, SIZE> where K: Eq + Hash {
+    /// Creates an empty map.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedMap;
+    ///
+    /// let map: FixedMap<u32, &str, 16> = FixedMap::new();
+    /// assert!(map.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            slots: [const { Slot::Empty }; SIZE],
+        }
+    }
+
+    /// Returns number of entries in the map.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedMap;
+    ///
+    /// let mut map: FixedMap<u32, &str, 4> = FixedMap::new();
+    /// map.insert(1, "one").unwrap();
+    /// map.insert(2, "two").unwrap();
+    /// map.insert(1, "uno").unwrap();
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedMap;
+    ///
+    /// let mut map: FixedMap<u32, &str, 4> = FixedMap::new();
+    /// assert!(map.is_empty());
+    ///
+    /// map.insert(1, "one").unwrap();
+    /// assert!(!map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the map's length has reached its capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedMap;
+    ///
+    /// let mut map: FixedMap<u32, &str, 1> = FixedMap::new();
+    /// assert!(!map.is_full());
+    ///
+    /// map.insert(1, "one").unwrap();
+    /// assert!(map.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.len == SIZE
+    }
+
+    /// Inserts a key-value pair into the map.
+    /// Returns the previous value if the key was already present.
+    /// Returns `Err(PushError((key, value)))` if the map is full and doesn't
+    /// contain the key, handing the pair back.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{FixedMap, error::PushError};
+    ///
+    /// let mut map: FixedMap<u32, &str, 2> = FixedMap::new();
+    /// assert_eq!(map.insert(1, "one"), Ok(None));
+    /// assert_eq!(map.insert(2, "two"), Ok(None));
+    ///
+    /// // Updating an existing key still works when full.
+    /// assert_eq!(map.insert(1, "uno"), Ok(Some("one")));
+    /// assert_eq!(map.get(&1), Some(&"uno"));
+    ///
+    /// assert_eq!(map.insert(3, "three"), Err(PushError((3, "three"))));
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, PushError<(K, V)>> {
+        match self.probe(&key) {
+            Probe::Found(index) => match &mut self.slots[index] {
+                Slot::Occupied(_, old) => Ok(Some(mem::replace(old, value))),
+                _ => unreachable!(),
+            },
+            Probe::Vacant(index) => {
+                self.slots[index] = Slot::Occupied(key, value);
+                self.len += 1;
+                Ok(None)
+            }
+            Probe::Full => Err(PushError((key, value))),
+        }
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if it isn't
+    /// present.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedMap;
+    ///
+    /// let mut map: FixedMap<u32, &str, 4> = FixedMap::new();
+    /// map.insert(1, "one").unwrap();
+    /// assert_eq!(map.get(&1), Some(&"one"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V> where K: Borrow<Q>, Q: Eq + Hash + ?Sized {
+        match self.probe(key) {
+            Probe::Found(index) => match &self.slots[index] {
+                Slot::Occupied(_, value) => Some(value),
+                _ => unreachable!(),
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if it
+    /// isn't present.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedMap;
+    ///
+    /// let mut map: FixedMap<u32, u32, 4> = FixedMap::new();
+    /// map.insert(1, 10).unwrap();
+    /// *map.get_mut(&1).unwrap() += 1;
+    /// assert_eq!(map.get(&1), Some(&11));
+    /// assert_eq!(map.get_mut(&2), None);
+    /// ```
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q>, Q: Eq + Hash + ?Sized {
+        match self.probe(key) {
+            Probe::Found(index) => match &mut self.slots[index] {
+                Slot::Occupied(_, value) => Some(value),
+                _ => unreachable!(),
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the map contains `key`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedMap;
+    ///
+    /// let mut map: FixedMap<u32, &str, 4> = FixedMap::new();
+    /// map.insert(1, "one").unwrap();
+    /// assert!(map.contains_key(&1));
+    /// assert!(!map.contains_key(&2));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool where K: Borrow<Q>, Q: Eq + Hash + ?Sized {
+        matches!(self.probe(key), Probe::Found(_))
+    }
+
+    /// Removes `key` from the map, returning its value if it was present.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedMap;
+    ///
+    /// let mut map: FixedMap<u32, &str, 2> = FixedMap::new();
+    /// map.insert(1, "one").unwrap();
+    /// map.insert(2, "two").unwrap();
+    ///
+    /// assert_eq!(map.remove(&1), Some("one"));
+    /// assert_eq!(map.remove(&1), None);
+    /// assert_eq!(map.get(&2), Some(&"two"));
+    ///
+    /// // The freed slot can be reused.
+    /// map.insert(3, "three").unwrap();
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V> where K: Borrow<Q>, Q: Eq + Hash + ?Sized {
+        let index: usize = match self.probe(key) {
+            Probe::Found(index) => index,
+            _ => return None,
+        };
+        let removed: Slot<K, V> = mem::replace(&mut self.slots[index], Slot::Tombstone);
+        self.len -= 1;
+        // When the next slot ends a probe sequence, so can this one, along
+        // with the tombstones right before it.
+        if matches!(self.slots[(index + 1) % SIZE], Slot::Empty) {
+            let mut index: usize = index;
+            while matches!(self.slots[index], Slot::Tombstone) {
+                self.slots[index] = Slot::Empty;
+                index = (index + SIZE - 1) % SIZE;
+            }
+        }
+        match removed {
+            Slot::Occupied(_, value) => Some(value),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns an iterator over the keys, in slot order.
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &K> {
+        self.slots.iter().filter_map(|slot| match slot {
+            Slot::Occupied(key, _) => Some(key),
+            _ => None,
+        })
+    }
+
+    /// Probes the slots for `key`, starting from its hash and wrapping around.
+    fn probe<Q>(&self, key: &Q) -> Probe where K: Borrow<Q>, Q: Eq + Hash + ?Sized {
+        if SIZE == 0 {
+            return Probe::Full;
+        }
+        let mut hasher: FnvHasher = FnvHasher::new();
+        key.hash(&mut hasher);
+        let start: usize = (hasher.finish() % SIZE as u64) as usize;
+        let mut vacant: Option<usize> = None;
+        for offset in 0..SIZE {
+            let index: usize = (start + offset) % SIZE;
+            match &self.slots[index] {
+                Slot::Empty => return Probe::Vacant(vacant.unwrap_or(index)),
+                Slot::Tombstone => {
+                    vacant.get_or_insert(index);
+                }
+                Slot::Occupied(existing, _) => {
+                    if existing.borrow() == key {
+                        return Probe::Found(index);
+                    }
+                }
+            }
+        }
+        match vacant {
+            Some(index) => Probe::Vacant(index),
+            None => Probe::Full,
+        }
+    }
+}
+
+impl<K, V, const SIZE: usize> Default for FixedMap<K, V, SIZE> where K: Eq + Hash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const SIZE: usize> Debug for FixedMap<K, V, SIZE> where K: Eq + Hash + Debug, V: Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.slots.iter().filter_map(|slot| match slot {
+                Slot::Occupied(key, value) => Some((key, value)),
+                _ => None,
+            }))
+            .finish()
+    }
+}
+
+/// 64-bit FNV-1a, so the map works without `std`'s hashers.
+struct FnvHasher(u64);
+
+impl FnvHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::String;
+
+    use super::*;
+
+    /// Every key hashes the same, so they all share one probe sequence.
+    #[derive(PartialEq, Eq, Debug)]
+    struct Colliding(u32);
+
+    impl Hash for Colliding {
+        fn hash<H: Hasher>(&self, _: &mut H) {}
+    }
+
+    /// Returns the slot every [`Colliding`] key starts probing from.
+    fn start<const SIZE: usize>() -> usize {
+        (FnvHasher::new().finish() % SIZE as u64) as usize
+    }
+
+    /// Renders the slots as `.` for empty, `T` for a tombstone and `O` for
+    /// an occupied slot.
+    fn layout<K, V, const SIZE: usize>(map: &FixedMap<K, V, SIZE>) -> String where K: Eq + Hash {
+        map.slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Empty => '.',
+                Slot::Tombstone => 'T',
+                Slot::Occupied(..) => 'O',
+            })
+            .collect()
+    }
+
+    #[test]
+    fn colliding_keys_stay_reachable_past_tombstones() {
+        let mut map: FixedMap<Colliding, u32, 4> = FixedMap::new();
+        for key in 0..4 {
+            map.insert(Colliding(key), key * 10).unwrap();
+        }
+        assert_eq!(map.remove(&Colliding(1)), Some(10));
+        assert_eq!(map.get(&Colliding(3)), Some(&30));
+        assert_eq!(map.get(&Colliding(1)), None);
+
+        // The tombstone is reused without duplicating existing keys.
+        assert_eq!(map.insert(Colliding(3), 31), Ok(Some(30)));
+        assert_eq!(map.insert(Colliding(4), 40), Ok(None));
+        assert!(map.is_full());
+        for key in [0, 2, 3, 4] {
+            assert!(map.contains_key(&Colliding(key)));
+        }
+    }
+
+    #[test]
+    fn remove_at_last_slot_clears_tombstones_before_it() {
+        let mut map: FixedMap<Colliding, u32, 8> = FixedMap::new();
+        assert_eq!(start::<8>(), 5);
+        for key in 0..3 {
+            map.insert(Colliding(key), key).unwrap();
+        }
+        assert_eq!(layout(&map), ".....OOO");
+
+        // The next slot is still occupied, so this leaves a tombstone.
+        assert_eq!(map.remove(&Colliding(1)), Some(1));
+        assert_eq!(layout(&map), ".....OTO");
+
+        // Slot 0 is empty, so the probe sequence can end at the last slot.
+        assert_eq!(map.remove(&Colliding(2)), Some(2));
+        assert_eq!(layout(&map), ".....O..");
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&Colliding(0)), Some(&0));
+    }
+
+    #[test]
+    fn remove_at_first_slot_clears_tombstones_wrapping_back() {
+        let mut map: FixedMap<Colliding, u32, 8> = FixedMap::new();
+        assert_eq!(start::<8>(), 5);
+        for key in 0..4 {
+            map.insert(Colliding(key), key).unwrap();
+        }
+        assert_eq!(layout(&map), "O....OOO");
+
+        map.remove(&Colliding(1)).unwrap();
+        map.remove(&Colliding(2)).unwrap();
+        assert_eq!(layout(&map), "O....OTT");
+
+        // Clearing slot 0 carries on through the tombstones at the end.
+        assert_eq!(map.remove(&Colliding(3)), Some(3));
+        assert_eq!(layout(&map), ".....O..");
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn table_without_empty_slots_still_works() {
+        let mut map: FixedMap<Colliding, u32, 4> = FixedMap::new();
+        assert_eq!(start::<4>(), 1);
+        for key in 0..4 {
+            map.insert(Colliding(key), key).unwrap();
+        }
+        map.remove(&Colliding(1)).unwrap();
+        map.remove(&Colliding(2)).unwrap();
+        assert_eq!(layout(&map), "OOTT");
+        assert_eq!(map.len(), 2);
+
+        // Lookups have to go all the way around to rule a key out.
+        assert_eq!(map.get(&Colliding(0)), Some(&0));
+        assert_eq!(map.get(&Colliding(3)), Some(&3));
+        assert!(!map.contains_key(&Colliding(1)));
+        assert_eq!(map.remove(&Colliding(1)), None);
+        assert_eq!(map.len(), 2);
+
+        // Existing keys are updated in place rather than put in a tombstone.
+        assert_eq!(map.insert(Colliding(3), 30), Ok(Some(3)));
+        assert_eq!(layout(&map), "OOTT");
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.insert(Colliding(5), 5), Ok(None));
+        assert_eq!(map.insert(Colliding(6), 6), Ok(None));
+        assert_eq!(layout(&map), "OOOO");
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.insert(Colliding(7), 7), Err(PushError((Colliding(7), 7))));
+        for (key, value) in [(0, 0), (3, 30), (5, 5), (6, 6)] {
+            assert_eq!(map.get(&Colliding(key)), Some(&value));
+        }
+    }
+}
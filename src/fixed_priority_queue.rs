@@ -0,0 +1,284 @@
+use core::{fmt::Debug, num::NonZero};
+
+use crate::{error::PushError, fixed_vec::FixedVec};
+
+/// A max-priority queue holding up to `SIZE` elements without allocating,
+/// implemented as a binary heap.
+pub struct FixedPriorityQueue<T, const SIZE: usize> where T: Ord {
+    heap: FixedVec<T, SIZE>,
+}
+
+impl<T, const SIZE: usize> FixedPriorityQueue<T, SIZE> where T: Ord {
+    /// Creates an empty priority queue.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedPriorityQueue;
+    ///
+    /// let queue: FixedPriorityQueue<u32, 16> = FixedPriorityQueue::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { heap: FixedVec::new() }
+    }
+
+    /// Returns number of elements in the priority queue.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedPriorityQueue;
+    ///
+    /// let mut queue: FixedPriorityQueue<u8, 4> = FixedPriorityQueue::new();
+    /// queue.push(1).unwrap();
+    /// queue.push(2).unwrap();
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if the priority queue is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedPriorityQueue;
+    ///
+    /// let mut queue: FixedPriorityQueue<u8, 4> = FixedPriorityQueue::new();
+    /// assert!(queue.is_empty());
+    ///
+    /// queue.push(1).unwrap();
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Inserts an element into the priority queue.
+    /// Returns `Err(PushError(value))` if the priority queue is full, handing
+    /// the value back.
+    /// Returns the new length otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{FixedPriorityQueue, error::PushError};
+    ///
+    /// let mut queue: FixedPriorityQueue<u8, 2> = FixedPriorityQueue::new();
+    /// assert_eq!(queue.push(1).unwrap().get(), 1);
+    /// assert_eq!(queue.push(3).unwrap().get(), 2);
+    /// assert_eq!(queue.push(2), Err(PushError(2)));
+    /// assert_eq!(queue.peek(), Some(&3));
+    /// ```
+    pub fn push(&mut self, value: T) -> Result<NonZero<usize>, PushError<T>> {
+        let len: NonZero<usize> = self.heap.push(value)?;
+        self.sift_up(len.get() - 1);
+        Ok(len)
+    }
+
+    /// Removes the greatest element and returns it, or `None` if the priority
+    /// queue is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedPriorityQueue;
+    ///
+    /// let mut queue: FixedPriorityQueue<u8, 4> = FixedPriorityQueue::new();
+    /// queue.push(1).unwrap();
+    /// queue.push(3).unwrap();
+    /// queue.push(2).unwrap();
+    ///
+    /// assert_eq!(queue.pop(), Some(3));
+    /// assert_eq!(queue.pop(), Some(2));
+    /// assert_eq!(queue.pop(), Some(1));
+    /// assert_eq!(queue.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let len: usize = self.heap.len();
+        if len == 0 {
+            return None;
+        }
+        self.heap.as_mut_slice().swap(0, len - 1);
+        let value: Option<T> = self.heap.pop().ok();
+        self.sift_down(0);
+        value
+    }
+
+    /// Returns a reference to the greatest element, or `None` if the priority
+    /// queue is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedPriorityQueue;
+    ///
+    /// let mut queue: FixedPriorityQueue<u8, 4> = FixedPriorityQueue::new();
+    /// assert_eq!(queue.peek(), None);
+    ///
+    /// queue.push(1).unwrap();
+    /// queue.push(5).unwrap();
+    /// queue.push(3).unwrap();
+    /// assert_eq!(queue.peek(), Some(&5));
+    /// assert_eq!(queue.len(), 3);
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.as_slice().first()
+    }
+
+    /// Moves the element at `index` up until its parent is no smaller.
+    fn sift_up(&mut self, mut index: usize) {
+        let heap: &mut [T] = self.heap.as_mut_slice();
+        while index > 0 {
+            let parent: usize = (index - 1) / 2;
+            if heap[index] <= heap[parent] {
+                break;
+            }
+            heap.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    /// Moves the element at `index` down until no child is greater.
+    fn sift_down(&mut self, mut index: usize) {
+        let heap: &mut [T] = self.heap.as_mut_slice();
+        loop {
+            let left: usize = 2 * index + 1;
+            let right: usize = left + 1;
+            let mut largest: usize = index;
+            if left < heap.len() && heap[left] > heap[largest] {
+                largest = left;
+            }
+            if right < heap.len() && heap[right] > heap[largest] {
+                largest = right;
+            }
+            if largest == index {
+                break;
+            }
+            heap.swap(index, largest);
+            index = largest;
+        }
+    }
+}
+
+impl<T, const SIZE: usize> Default for FixedPriorityQueue<T, SIZE> where T: Ord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const SIZE: usize> Debug for FixedPriorityQueue<T, SIZE> where T: Ord + Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedPriorityQueue")
+            .field("heap", &self.heap)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+
+    /// Rearranges `values` into the next permutation in lexicographic order,
+    /// returning `false` once they're back in ascending order.
+    fn next_permutation(values: &mut [u8]) -> bool {
+        let Some(pivot) = values.windows(2).rposition(|pair| pair[0] < pair[1]) else {
+            values.reverse();
+            return false;
+        };
+        let successor: usize = values.iter().rposition(|value| *value > values[pivot]).unwrap();
+        values.swap(pivot, successor);
+        values[pivot + 1..].reverse();
+        true
+    }
+
+    /// Panics unless every element is no greater than its parent.
+    fn assert_heap<T, const SIZE: usize>(queue: &FixedPriorityQueue<T, SIZE>) where T: Ord + Debug {
+        let heap: &[T] = queue.heap.as_slice();
+        for index in 1..heap.len() {
+            assert!(heap[index] <= heap[(index - 1) / 2], "not a heap: {heap:?}");
+        }
+    }
+
+    /// Pushes `values` into a queue filling it to capacity, then returns
+    /// everything it pops.
+    fn fill_and_drain<const SIZE: usize>(values: &[u8]) -> Vec<u8> {
+        let mut queue: FixedPriorityQueue<u8, SIZE> = FixedPriorityQueue::new();
+        for value in values {
+            queue.push(*value).unwrap();
+            assert_heap(&queue);
+        }
+        assert_eq!(queue.push(u8::MAX), Err(PushError(u8::MAX)));
+
+        let mut popped: Vec<u8> = Vec::new();
+        while let Some(value) = queue.pop() {
+            popped.push(value);
+            assert_heap(&queue);
+        }
+        popped
+    }
+
+    /// Runs [`fill_and_drain`] over every ordering of `values`.
+    fn drains_in_descending_order<const SIZE: usize>(mut values: [u8; SIZE]) {
+        values.sort();
+        let mut expected: [u8; SIZE] = values;
+        expected.reverse();
+        loop {
+            assert_eq!(fill_and_drain::<SIZE>(&values), expected, "pushed {values:?}");
+            if !next_permutation(&mut values) {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn pop_drains_every_permutation_in_descending_order() {
+        drains_in_descending_order([0]);
+        drains_in_descending_order([0, 1]);
+        drains_in_descending_order([0, 1, 2]);
+        drains_in_descending_order([0, 1, 2, 3]);
+        drains_in_descending_order([0, 1, 2, 3, 4, 5, 6]);
+        drains_in_descending_order([0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn pop_drains_duplicates_in_descending_order() {
+        drains_in_descending_order([1, 1]);
+        drains_in_descending_order([0, 1, 1, 2, 2]);
+        drains_in_descending_order([5, 1, 7, 3, 7, 0, 4, 2]);
+    }
+
+    #[test]
+    fn single_slot_queue_holds_one_element() {
+        let mut queue: FixedPriorityQueue<u8, 1> = FixedPriorityQueue::new();
+        assert_eq!(queue.push(4).unwrap().get(), 1);
+        assert_eq!(queue.push(9), Err(PushError(9)));
+        assert_eq!(queue.peek(), Some(&4));
+        assert_eq!(queue.pop(), Some(4));
+        assert_eq!(queue.pop(), None);
+
+        let mut empty: FixedPriorityQueue<u8, 0> = FixedPriorityQueue::new();
+        assert_eq!(empty.push(1), Err(PushError(1)));
+        assert_eq!(empty.pop(), None);
+    }
+
+    #[test]
+    fn interleaved_pushes_and_pops_keep_the_heap_ordered() {
+        let mut queue: FixedPriorityQueue<u8, 16> = FixedPriorityQueue::new();
+        let mut shadow: Vec<u8> = Vec::new();
+        // A small linear congruential generator, so the run is repeatable.
+        let mut state: u32 = 1;
+        for _ in 0..1000 {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let value: u8 = (state >> 16) as u8;
+            if value.is_multiple_of(3) || queue.len() == 16 {
+                shadow.sort();
+                assert_eq!(queue.pop(), shadow.pop());
+            } else {
+                queue.push(value).unwrap();
+                shadow.push(value);
+            }
+            assert_heap(&queue);
+            assert_eq!(queue.len(), shadow.len());
+        }
+    }
+}
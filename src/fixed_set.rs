@@ -0,0 +1,198 @@
+use core::{borrow::Borrow, fmt::Debug, hash::Hash};
+
+use crate::{error::PushError, fixed_map::FixedMap};
+
+/// A hash set holding up to `SIZE` values without allocating, built on
+/// [`FixedMap`].
+pub struct FixedSet<T, const SIZE: usize> where T: Eq + Hash {
+    map: FixedMap<T, (), SIZE>,
+}
+
+impl<T, const SIZE: usize> FixedSet<T, SIZE> where T: Eq + Hash {
+    /// Creates an empty set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedSet;
+    ///
+    /// let set: FixedSet<u32, 16> = FixedSet::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { map: FixedMap::new() }
+    }
+
+    /// Returns number of values in the set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedSet;
+    ///
+    /// let mut set: FixedSet<u32, 4> = FixedSet::new();
+    /// set.insert(1).unwrap();
+    /// set.insert(2).unwrap();
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedSet;
+    ///
+    /// let mut set: FixedSet<u32, 4> = FixedSet::new();
+    /// assert!(set.is_empty());
+    ///
+    /// set.insert(1).unwrap();
+    /// assert!(!set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Adds a value to the set.
+    /// Returns whether the value was newly added.
+    /// Returns `Err(PushError(value))` if the set is full and doesn't contain
+    /// the value, handing it back.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{FixedSet, error::PushError};
+    ///
+    /// let mut set: FixedSet<u32, 2> = FixedSet::new();
+    /// assert_eq!(set.insert(1), Ok(true));
+    /// assert_eq!(set.insert(1), Ok(false));
+    /// assert_eq!(set.len(), 1);
+    ///
+    /// assert_eq!(set.insert(2), Ok(true));
+    /// assert_eq!(set.insert(2), Ok(false));
+    /// assert_eq!(set.insert(3), Err(PushError(3)));
+    /// ```
+    pub fn insert(&mut self, value: T) -> Result<bool, PushError<T>> {
+        match self.map.insert(value, ()) {
+            Ok(previous) => Ok(previous.is_none()),
+            Err(PushError((value, ()))) => Err(PushError(value)),
+        }
+    }
+
+    /// Returns `true` if the set contains `value`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedSet;
+    ///
+    /// let mut set: FixedSet<u32, 4> = FixedSet::new();
+    /// set.insert(1).unwrap();
+    /// assert!(set.contains(&1));
+    /// assert!(!set.contains(&2));
+    /// ```
+    pub fn contains<Q>(&self, value: &Q) -> bool where T: Borrow<Q>, Q: Eq + Hash + ?Sized {
+        self.map.contains_key(value)
+    }
+
+    /// Removes `value` from the set, returning whether it was present.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedSet;
+    ///
+    /// let mut set: FixedSet<u32, 1> = FixedSet::new();
+    /// set.insert(1).unwrap();
+    /// assert!(set.remove(&1));
+    /// assert!(!set.remove(&1));
+    ///
+    /// // The freed slot can be reused.
+    /// assert_eq!(set.insert(2), Ok(true));
+    /// ```
+    pub fn remove<Q>(&mut self, value: &Q) -> bool where T: Borrow<Q>, Q: Eq + Hash + ?Sized {
+        self.map.remove(value).is_some()
+    }
+}
+
+impl<T, const SIZE: usize> Default for FixedSet<T, SIZE> where T: Eq + Hash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const SIZE: usize> Debug for FixedSet<T, SIZE> where T: Eq + Hash + Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.map.keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_inserts_are_stored_once() {
+        let mut set: FixedSet<u32, 4> = FixedSet::new();
+        assert_eq!(set.insert(1), Ok(true));
+        assert_eq!(set.insert(1), Ok(false));
+        assert_eq!(set.insert(2), Ok(true));
+        assert_eq!(set.insert(1), Ok(false));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&1) && set.contains(&2));
+    }
+
+    #[test]
+    fn duplicate_insert_into_full_set_succeeds() {
+        let mut set: FixedSet<u32, 2> = FixedSet::new();
+        set.insert(1).unwrap();
+        set.insert(2).unwrap();
+
+        // Already present, so there's no need for room.
+        assert_eq!(set.insert(2), Ok(false));
+        assert_eq!(set.insert(3), Err(PushError(3)));
+        assert_eq!(set.len(), 2);
+        assert!(!set.contains(&3));
+    }
+
+    #[test]
+    fn removed_values_are_gone_and_free_their_slot() {
+        let mut set: FixedSet<u32, 3> = FixedSet::new();
+        for value in [1, 2, 3] {
+            set.insert(value).unwrap();
+        }
+        assert_eq!(set.insert(4), Err(PushError(4)));
+
+        assert!(set.remove(&2));
+        assert!(!set.remove(&2));
+        assert!(!set.contains(&2));
+        assert_eq!(set.len(), 2);
+
+        assert_eq!(set.insert(4), Ok(true));
+        assert_eq!(set.insert(2), Err(PushError(2)));
+        for value in [1, 3, 4] {
+            assert!(set.contains(&value));
+        }
+    }
+
+    #[test]
+    fn emptied_set_can_be_refilled() {
+        let mut set: FixedSet<u32, 4> = FixedSet::new();
+        for round in 0..3 {
+            for value in 0..4 {
+                assert_eq!(set.insert(round * 10 + value), Ok(true));
+            }
+            assert_eq!(set.insert(99), Err(PushError(99)));
+            for value in 0..4 {
+                assert!(set.remove(&(round * 10 + value)));
+            }
+            assert!(set.is_empty());
+        }
+    }
+
+    #[test]
+    fn zero_capacity_rejects_every_insert() {
+        let mut set: FixedSet<u32, 0> = FixedSet::new();
+        assert_eq!(set.insert(1), Err(PushError(1)));
+        assert!(!set.contains(&1));
+        assert!(!set.remove(&1));
+    }
+}
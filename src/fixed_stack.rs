@@ -0,0 +1,234 @@
+use core::{fmt::Debug, num::NonZero};
+
+use crate::{error::PushError, fixed_vec::FixedVec};
+
+/// A last-in, first-out stack holding up to `SIZE` elements without
+/// allocating.
+pub struct FixedStack<T, const SIZE: usize> {
+    items: FixedVec<T, SIZE>,
+}
+
+impl<T, const SIZE: usize> FixedStack<T, SIZE> {
+    /// Creates an empty stack.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedStack;
+    ///
+    /// let stack: FixedStack<u32, 16> = FixedStack::new();
+    /// assert!(stack.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { items: FixedVec::new() }
+    }
+
+    /// Returns number of elements in the stack.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedStack;
+    ///
+    /// let mut stack: FixedStack<u8, 4> = FixedStack::new();
+    /// stack.push(1).unwrap();
+    /// stack.push(2).unwrap();
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the stack is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedStack;
+    ///
+    /// let mut stack: FixedStack<u8, 4> = FixedStack::new();
+    /// assert!(stack.is_empty());
+    ///
+    /// stack.push(1).unwrap();
+    /// assert!(!stack.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns `true` if the stack's length has reached its capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedStack;
+    ///
+    /// let mut stack: FixedStack<u8, 1> = FixedStack::new();
+    /// assert!(!stack.is_full());
+    ///
+    /// stack.push(1).unwrap();
+    /// assert!(stack.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.items.is_full()
+    }
+
+    /// Pushes an element onto the top of the stack.
+    /// Returns `Err(PushError(value))` if the stack is full, handing the value
+    /// back.
+    /// Returns the new length otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{FixedStack, error::PushError};
+    ///
+    /// let mut stack: FixedStack<u8, 2> = FixedStack::new();
+    /// assert_eq!(stack.push(1).unwrap().get(), 1);
+    /// assert_eq!(stack.push(2).unwrap().get(), 2);
+    /// assert_eq!(stack.push(3), Err(PushError(3)));
+    /// assert_eq!(stack.peek(), Some(&2));
+    /// ```
+    pub fn push(&mut self, value: T) -> Result<NonZero<usize>, PushError<T>> {
+        self.items.push(value)
+    }
+
+    /// Removes the top element and returns it, or `None` if the stack is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedStack;
+    ///
+    /// let mut stack: FixedStack<u8, 4> = FixedStack::new();
+    /// stack.push(1).unwrap();
+    /// stack.push(2).unwrap();
+    /// stack.push(3).unwrap();
+    ///
+    /// assert_eq!(stack.pop(), Some(3));
+    /// assert_eq!(stack.pop(), Some(2));
+    /// assert_eq!(stack.pop(), Some(1));
+    /// assert_eq!(stack.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop().ok()
+    }
+
+    /// Returns a reference to the top element, or `None` if the stack is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedStack;
+    ///
+    /// let mut stack: FixedStack<u8, 4> = FixedStack::new();
+    /// assert_eq!(stack.peek(), None);
+    ///
+    /// stack.push(1).unwrap();
+    /// stack.push(2).unwrap();
+    /// assert_eq!(stack.peek(), Some(&2));
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.items.as_slice().last()
+    }
+
+    /// Returns a mutable reference to the top element, or `None` if the stack
+    /// is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedStack;
+    ///
+    /// let mut stack: FixedStack<u8, 4> = FixedStack::new();
+    /// assert_eq!(stack.peek_mut(), None);
+    ///
+    /// stack.push(1).unwrap();
+    /// *stack.peek_mut().unwrap() = 5;
+    /// assert_eq!(stack.pop(), Some(5));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.items.as_mut_slice().last_mut()
+    }
+}
+
+impl<T, const SIZE: usize> Default for FixedStack<T, SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const SIZE: usize> Debug for FixedStack<T, SIZE> where T: Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedStack")
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pop_returns_elements_last_in_first_out() {
+        let mut stack: FixedStack<u8, 4> = FixedStack::new();
+        for value in 0..4 {
+            stack.push(value).unwrap();
+        }
+        for value in (0..4).rev() {
+            assert_eq!(stack.peek(), Some(&value));
+            assert_eq!(stack.pop(), Some(value));
+        }
+        assert_eq!(stack.pop(), None);
+
+        // Interleaved pushes and pops still come back newest first.
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert_eq!(stack.pop(), Some(2));
+        stack.push(3).unwrap();
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(1));
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn push_hands_the_value_back_when_full() {
+        let mut stack: FixedStack<u8, 2> = FixedStack::new();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert!(stack.is_full());
+        assert_eq!(stack.push(3), Err(PushError(3)));
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.peek(), Some(&2));
+
+        let mut empty: FixedStack<u8, 0> = FixedStack::new();
+        assert_eq!(empty.push(1), Err(PushError(1)));
+        assert_eq!(empty.peek(), None);
+    }
+
+    #[test]
+    fn peek_leaves_the_stack_untouched() {
+        let mut stack: FixedStack<u8, 4> = FixedStack::new();
+        assert_eq!(stack.peek(), None);
+        assert_eq!(stack.peek_mut(), None);
+
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert_eq!(stack.peek(), Some(&2));
+        assert_eq!(stack.peek(), Some(&2));
+        assert_eq!(stack.len(), 2);
+    }
+
+    #[test]
+    fn peek_mut_changes_what_pop_returns() {
+        let mut stack: FixedStack<u8, 4> = FixedStack::new();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+
+        *stack.peek_mut().unwrap() = 20;
+        assert_eq!(stack.peek(), Some(&20));
+        assert_eq!(stack.pop(), Some(20));
+
+        // Only the top element is handed out.
+        *stack.peek_mut().unwrap() += 9;
+        assert_eq!(stack.pop(), Some(10));
+        assert_eq!(stack.pop(), None);
+    }
+}
@@ -0,0 +1,238 @@
+use core::fmt::{self, Debug, Display, Write};
+
+use crate::error::FullCollectionError;
+
+/// A UTF-8 string holding up to `SIZE` bytes without allocating.
+///
+/// Pushes are all-or-nothing, so a multibyte character is never split at the
+/// capacity boundary and the contents always stay valid UTF-8.
+#[derive(Clone, Copy)]
+pub struct FixedString<const SIZE: usize> {
+    len: usize,
+    // Only the first `len` bytes are meaningful, and they're valid UTF-8.
+    bytes: [u8; SIZE],
+}
+
+impl<const SIZE: usize> FixedString<SIZE> {
+    /// Creates an empty string.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedString;
+    ///
+    /// let string: FixedString<16> = FixedString::new();
+    /// assert_eq!(string.as_str(), "");
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { len: 0, bytes: [0; SIZE] }
+    }
+
+    /// Returns the length of the string in bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedString;
+    ///
+    /// let mut string: FixedString<16> = FixedString::new();
+    /// string.push_str("héllo").unwrap();
+    /// assert_eq!(string.len(), 6);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the string is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedString;
+    ///
+    /// let mut string: FixedString<16> = FixedString::new();
+    /// assert!(string.is_empty());
+    ///
+    /// string.push('a').unwrap();
+    /// assert!(!string.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a string slice.
+    /// Returns `Err(FullCollectionError)` if it doesn't fit in the remaining
+    /// capacity, in which case nothing is appended.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedString;
+    ///
+    /// let mut string: FixedString<4> = FixedString::new();
+    /// string.push_str("ab").unwrap();
+    /// assert!(string.push_str("cde").is_err());
+    /// assert_eq!(string.as_str(), "ab");
+    /// ```
+    pub fn push_str(&mut self, string: &str) -> Result<(), FullCollectionError> {
+        let end: usize = self.len + string.len();
+        if end > SIZE {
+            return Err(FullCollectionError);
+        }
+        self.bytes[self.len..end].copy_from_slice(string.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+
+    /// Appends a character.
+    /// Returns `Err(FullCollectionError)` if its UTF-8 encoding doesn't fit in
+    /// the remaining capacity, in which case nothing is appended.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedString;
+    ///
+    /// let mut string: FixedString<1> = FixedString::new();
+    /// string.push('a').unwrap();
+    /// assert!(string.push('b').is_err());
+    /// assert_eq!(string.as_str(), "a");
+    /// ```
+    pub fn push(&mut self, value: char) -> Result<(), FullCollectionError> {
+        self.push_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Returns the contents as a string slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedString;
+    ///
+    /// let mut string: FixedString<16> = FixedString::new();
+    /// string.push_str("ring").unwrap();
+    /// assert_eq!(string.as_str(), "ring");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Only whole string slices are ever copied in, so the first
+        // len bytes are valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+
+    /// Empties the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedString;
+    ///
+    /// let mut string: FixedString<16> = FixedString::new();
+    /// string.push_str("ring").unwrap();
+    /// string.clear();
+    /// assert!(string.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const SIZE: usize> Default for FixedString<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize> Debug for FixedString<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const SIZE: usize> Display for FixedString<SIZE> {
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedString;
+    ///
+    /// let mut string: FixedString<16> = FixedString::new();
+    /// string.push_str("ring").unwrap();
+    /// assert_eq!(format!("[{string}]"), "[ring]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const SIZE: usize> Write for FixedString<SIZE> {
+    /// Appends `string`, failing with `fmt::Error` if it doesn't fit.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use fixed_collections::FixedString;
+    ///
+    /// let mut string: FixedString<8> = FixedString::new();
+    /// write!(string, "{}-{}", 12, 34).unwrap();
+    /// assert_eq!(string.as_str(), "12-34");
+    /// assert!(write!(string, "{}", 5678).is_err());
+    /// ```
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push_str(string).map_err(|_| fmt::Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+
+    use super::*;
+
+    #[test]
+    fn push_str_of_multibyte_text_fills_capacity_exactly() {
+        let mut string: FixedString<5> = FixedString::new();
+        string.push_str("ab").unwrap();
+        // "€" is 3 bytes, exactly filling the capacity.
+        string.push_str("€").unwrap();
+        assert_eq!(string.as_str(), "ab€");
+        assert!(string.push_str("c").is_err());
+    }
+
+    #[test]
+    fn push_str_never_splits_a_multibyte_character() {
+        let mut string: FixedString<4> = FixedString::new();
+        string.push_str("ab").unwrap();
+        assert!(string.push_str("€").is_err());
+        assert_eq!(string.as_str(), "ab");
+        assert_eq!(string.len(), 2);
+    }
+
+    #[test]
+    fn push_of_multibyte_char_fills_capacity_exactly() {
+        let mut string: FixedString<3> = FixedString::new();
+        string.push('a').unwrap();
+        // 'é' is 2 bytes, exactly filling the capacity.
+        string.push('é').unwrap();
+        assert_eq!(string.as_str(), "aé");
+        assert!(string.push('b').is_err());
+    }
+
+    #[test]
+    fn push_never_splits_a_multibyte_character() {
+        let mut string: FixedString<2> = FixedString::new();
+        string.push('a').unwrap();
+        assert!(string.push('é').is_err());
+        assert_eq!(string.as_str(), "a");
+
+        let mut empty: FixedString<0> = FixedString::new();
+        assert!(empty.push('a').is_err());
+        assert_eq!(empty.as_str(), "");
+    }
+
+    #[test]
+    fn write_of_overflowing_multibyte_char_leaves_string_unchanged() {
+        let mut string: FixedString<4> = FixedString::new();
+        let euro: char = '€';
+        write!(string, "ab").unwrap();
+        assert!(write!(string, "{euro}").is_err());
+        assert!(write!(string, "€").is_err());
+        assert_eq!(string.as_str(), "ab");
+
+        // What still fits can be written afterwards.
+        let e_acute: char = 'é';
+        write!(string, "{e_acute}").unwrap();
+        assert_eq!(string.as_str(), "abé");
+    }
+}
@@ -0,0 +1,408 @@
+use core::{fmt::Debug, mem::MaybeUninit, num::NonZero, ops::{Index, IndexMut}, slice::SliceIndex};
+
+use crate::{error::{EmptyCollectionError, PushError}};
+
+/// A contiguous vector that can grow up to `SIZE` elements without
+/// allocating.
+pub struct FixedVec<T, const SIZE: usize> {
+    len: usize,
+    // Only the first `len` slots are initialized.
+    buffer: [MaybeUninit<T>; SIZE],
+}
+
+impl<T, const SIZE: usize> FixedVec<T, SIZE> {
+    /// Creates an empty vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let vec: FixedVec<u32, 16> = FixedVec::new();
+    /// assert!(vec.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            buffer: [const { MaybeUninit::uninit() }; SIZE],
+        }
+    }
+
+    /// Returns number of elements in the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 4> = FixedVec::new();
+    /// assert_eq!(vec.len(), 0);
+    ///
+    /// vec.push(1).unwrap();
+    /// vec.push(2).unwrap();
+    /// assert_eq!(vec.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 4> = FixedVec::new();
+    /// assert!(vec.is_empty());
+    ///
+    /// vec.push(1).unwrap();
+    /// assert!(!vec.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the vector's length has reached its capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 2> = FixedVec::new();
+    /// assert!(!vec.is_full());
+    ///
+    /// vec.push(1).unwrap();
+    /// vec.push(2).unwrap();
+    /// assert!(vec.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.len == SIZE
+    }
+
+    /// Appends an element to the back of the vector.
+    /// Returns `Err(PushError(value))` if the vector is full, handing the
+    /// value back.
+    /// Returns the new length otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{FixedVec, error::PushError};
+    ///
+    /// let mut vec: FixedVec<u8, 2> = FixedVec::new();
+    /// assert_eq!(vec.push(1).unwrap().get(), 1);
+    /// assert_eq!(vec.push(2).unwrap().get(), 2);
+    /// assert_eq!(vec.push(3), Err(PushError(3)));
+    /// assert_eq!(vec.as_slice(), [1, 2]);
+    /// ```
+    pub fn push(&mut self, value: T) -> Result<NonZero<usize>, PushError<T>> {
+        if self.is_full() {
+            return Err(PushError(value));
+        }
+        self.buffer[self.len].write(value);
+        self.len += 1;
+        // SAFETY: self.len must be > 0.
+        Ok(NonZero::new(self.len).unwrap())
+    }
+
+    /// Removes the last element and returns it.
+    /// If the vector is empty, we return Err(EmptyCollectionError)
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 4> = FixedVec::new();
+    /// assert!(vec.pop().is_err());
+    ///
+    /// vec.push(1).unwrap();
+    /// vec.push(2).unwrap();
+    /// assert_eq!(vec.pop().unwrap(), 2);
+    /// assert_eq!(vec.pop().unwrap(), 1);
+    /// assert!(vec.pop().is_err());
+    /// ```
+    pub fn pop(&mut self) -> Result<T, EmptyCollectionError> {
+        if self.is_empty() {
+            return Err(EmptyCollectionError);
+        }
+        self.len -= 1;
+        // SAFETY: The slot was the last live one, it's no longer counted as
+        // live now len has shrunk.
+        Ok(unsafe { self.buffer[self.len].assume_init_read() })
+    }
+
+    /// Returns the elements as a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 4> = FixedVec::new();
+    /// assert_eq!(vec.as_slice(), []);
+    ///
+    /// vec.push(1).unwrap();
+    /// vec.push(2).unwrap();
+    /// assert_eq!(vec.as_slice(), [1, 2]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The first len slots hold live elements.
+        unsafe { self.buffer[..self.len].assume_init_ref() }
+    }
+
+    /// Returns the elements as a mutable slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 4> = FixedVec::new();
+    /// vec.push(2).unwrap();
+    /// vec.push(1).unwrap();
+    ///
+    /// vec.as_mut_slice().sort();
+    /// assert_eq!(vec.as_slice(), [1, 2]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The first len slots hold live elements.
+        unsafe { self.buffer[..self.len].assume_init_mut() }
+    }
+
+    /// Removes and drops every element, leaving the vector empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 4> = FixedVec::new();
+    /// vec.push(1).unwrap();
+    /// vec.clear();
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        while self.pop().is_ok() {}
+    }
+}
+
+impl<T, const SIZE: usize> Default for FixedVec<T, SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const SIZE: usize> Debug for FixedVec<T, SIZE> where T: Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, const SIZE: usize> Drop for FixedVec<T, SIZE> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, I, const SIZE: usize> Index<I> for FixedVec<T, SIZE> where I: SliceIndex<[T]> {
+    type Output = I::Output;
+
+    /// Indexes into the elements like a slice.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds of the live elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 4> = FixedVec::new();
+    /// vec.push(1).unwrap();
+    /// vec.push(2).unwrap();
+    /// vec.push(3).unwrap();
+    ///
+    /// assert_eq!(vec[0], 1);
+    /// assert_eq!(vec[1..], [2, 3]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 4> = FixedVec::new();
+    /// vec.push(1).unwrap();
+    /// let _ = vec[1];
+    /// ```
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, I, const SIZE: usize> IndexMut<I> for FixedVec<T, SIZE> where I: SliceIndex<[T]> {
+    /// Mutably indexes into the elements like a slice.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds of the live elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::FixedVec;
+    ///
+    /// let mut vec: FixedVec<u8, 4> = FixedVec::new();
+    /// vec.push(1).unwrap();
+    /// vec.push(2).unwrap();
+    ///
+    /// vec[0] = 5;
+    /// assert_eq!(vec.as_slice(), [5, 2]);
+    /// ```
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    /// Bumps a shared counter when dropped, so tests can check that every
+    /// element is dropped exactly once. The id tells elements apart.
+    struct DropCounter<'a> {
+        drops: &'a Cell<usize>,
+        id: u8,
+    }
+
+    impl<'a> DropCounter<'a> {
+        fn new(drops: &'a Cell<usize>, id: u8) -> Self {
+            Self { drops, id }
+        }
+    }
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[test]
+    fn pop_returns_elements_in_reverse_push_order() {
+        let mut vec: FixedVec<u8, 4> = FixedVec::new();
+        for value in 0..4 {
+            assert_eq!(vec.push(value).unwrap().get(), usize::from(value) + 1);
+        }
+        for value in (0..4).rev() {
+            assert_eq!(vec.pop().unwrap(), value);
+        }
+        assert!(vec.pop().is_err());
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn push_hands_the_value_back_when_full() {
+        let mut vec: FixedVec<u8, 2> = FixedVec::new();
+        vec.push(1).unwrap();
+        vec.push(2).unwrap();
+        assert_eq!(vec.push(3), Err(PushError(3)));
+        assert_eq!(vec.as_slice(), [1, 2]);
+
+        // Room again after a pop.
+        vec.pop().unwrap();
+        assert!(vec.push(3).is_ok());
+        assert_eq!(vec.as_slice(), [1, 3]);
+
+        let mut empty: FixedVec<u8, 0> = FixedVec::new();
+        assert!(empty.is_empty() && empty.is_full());
+        assert_eq!(empty.push(1), Err(PushError(1)));
+    }
+
+    #[test]
+    fn full_push_drops_nothing() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut vec: FixedVec<DropCounter<'_>, 1> = FixedVec::new();
+        assert!(vec.push(DropCounter::new(&drops, 0)).is_ok());
+
+        let PushError(rejected) = vec.push(DropCounter::new(&drops, 1)).err().unwrap();
+        assert_eq!(rejected.id, 1);
+        assert_eq!(drops.get(), 0);
+        drop(rejected);
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn slices_and_indexing_see_only_live_elements() {
+        let mut vec: FixedVec<u8, 8> = FixedVec::new();
+        assert_eq!(vec.as_slice(), []);
+        for value in [3, 1, 2] {
+            vec.push(value).unwrap();
+        }
+
+        vec.as_mut_slice().sort();
+        assert_eq!(vec.as_slice(), [1, 2, 3]);
+        assert_eq!(vec[0], 1);
+        assert_eq!(vec[1..], [2, 3]);
+        vec[2] = 9;
+        vec[..2].copy_from_slice(&[7, 8]);
+        assert_eq!(vec.as_slice(), [7, 8, 9]);
+
+        vec.pop().unwrap();
+        assert_eq!(vec.as_slice(), [7, 8]);
+        assert_eq!(vec.as_mut_slice().len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn indexing_past_len_panics() {
+        let mut vec: FixedVec<u8, 4> = FixedVec::new();
+        vec.push(1).unwrap();
+        let _ = vec[1];
+    }
+
+    #[test]
+    fn pop_moves_out_without_dropping() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut vec: FixedVec<DropCounter<'_>, 4> = FixedVec::new();
+        for id in 0..3 {
+            assert!(vec.push(DropCounter::new(&drops, id)).is_ok());
+        }
+
+        let popped: DropCounter<'_> = vec.pop().unwrap();
+        assert_eq!(popped.id, 2);
+        assert_eq!(drops.get(), 0);
+        drop(popped);
+        assert_eq!(drops.get(), 1);
+
+        drop(vec);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn clear_drops_each_element_once() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut vec: FixedVec<DropCounter<'_>, 4> = FixedVec::new();
+        for id in 0..4 {
+            assert!(vec.push(DropCounter::new(&drops, id)).is_ok());
+        }
+
+        vec.clear();
+        assert!(vec.is_empty());
+        assert_eq!(drops.get(), 4);
+
+        // Clearing again, or dropping the empty vector, drops nothing more.
+        vec.clear();
+        drop(vec);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn drop_touches_only_live_elements() {
+        // One counter per element, to check none is dropped twice.
+        let drops: [Cell<usize>; 4] = Default::default();
+        let mut vec: FixedVec<DropCounter<'_>, 8> = FixedVec::new();
+        for (id, counter) in (0..).zip(&drops) {
+            assert!(vec.push(DropCounter::new(counter, id)).is_ok());
+        }
+        let popped: DropCounter<'_> = vec.pop().unwrap();
+
+        drop(vec);
+        assert!(drops[..3].iter().all(|counter| counter.get() == 1));
+        assert_eq!(drops[3].get(), 0);
+
+        drop(popped);
+        assert_eq!(drops[3].get(), 1);
+    }
+}
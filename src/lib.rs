@@ -1,2 +1,40 @@
+//! Fixed-capacity collections that never allocate.
+//!
+//! The crate is `no_std`. The `std` feature (enabled by default) adds
+//! [`std::error::Error`] impls for the error types, and implies the `alloc`
+//! feature which enables conversions into heap-allocated collections. It also
+//! adds [`SyncRingBuffer`], which needs `std`'s `Mutex`. The opt-in
+//! `generation` feature adds `RingBuffer::generation`, a modification
+//! counter.
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
 pub mod ring_buffer;
-pub mod error;
\ No newline at end of file
+pub mod fixed_vec;
+pub mod fixed_stack;
+pub mod fixed_priority_queue;
+pub mod fixed_map;
+pub mod fixed_set;
+pub mod fixed_bit_set;
+pub mod fixed_string;
+#[cfg(feature = "std")]
+pub mod sync_ring_buffer;
+pub mod error;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+pub use ring_buffer::RingBuffer;
+pub use fixed_vec::FixedVec;
+pub use fixed_stack::FixedStack;
+pub use fixed_priority_queue::FixedPriorityQueue;
+pub use fixed_map::FixedMap;
+pub use fixed_set::FixedSet;
+pub use fixed_bit_set::FixedBitSet;
+pub use fixed_string::FixedString;
+#[cfg(feature = "std")]
+pub use sync_ring_buffer::SyncRingBuffer;
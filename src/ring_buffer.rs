@@ -1,28 +1,80 @@
-use std::{fmt::Debug, num::NonZero, ops::{Index, IndexMut}};
+use core::{cmp::Ordering, fmt::{Debug, Display}, hash::{Hash, Hasher}, marker::PhantomData, mem::{self, MaybeUninit}, num::NonZero, ops::{Index, IndexMut, Range}, slice};
 
-use crate::{error::{EmptyCollectionError, FullCollectionError}};
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
 
+use crate::{error::{AppendError, EmptyCollectionError, FullCollectionError, PushError}};
+
+/// A double-ended queue holding up to `SIZE` elements without allocating.
+///
+/// Elements live in a fixed array of possibly uninitialized slots. Only the
+/// `len` slots starting at the front, wrapping around the end of the array,
+/// are initialized, so no slot needs a flag marking it occupied and elements
+/// are stored at their own size.
+///
+/// # Examples
+#[cfg_attr(not(feature = "generation"), doc = "```")]
+#[cfg_attr(feature = "generation", doc = "```ignore")]
+/// use core::mem::size_of;
+/// use fixed_collections::RingBuffer;
+///
+/// // Just the elements, plus the head and len indices.
+/// assert_eq!(size_of::<RingBuffer<u8, 64>>(), 64 + 2 * size_of::<usize>());
+/// assert!(size_of::<RingBuffer<u8, 64>>() < size_of::<[Option<u8>; 64]>() + 2 * size_of::<usize>());
+/// ```
+///
+/// A zero capacity ring buffer is always both empty and full:
+/// ```
+/// use fixed_collections::{RingBuffer, error::PushError};
+///
+/// let mut ring: RingBuffer<u8, 0> = RingBuffer::new();
+/// assert!(ring.is_empty() && ring.is_full());
+/// assert_eq!(ring.push_back(1), Err(PushError(1)));
+/// ```
 pub struct RingBuffer<T, const SIZE: usize> {
     head: usize,
     len: usize,
-    buffer: [Option<T>; SIZE],
+    // Only the `len` slots starting at `head` (wrapping around) are
+    // initialized.
+    buffer: [MaybeUninit<T>; SIZE],
+    // Bumped by every operation changing the stored elements or layout.
+    #[cfg(feature = "generation")]
+    generation: u64,
 }
 
 impl<T, const SIZE: usize> RingBuffer<T, SIZE> {
+    /// The maximum number of elements the ring buffer can hold, readable
+    /// without an instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// assert_eq!(RingBuffer::<u8, 32>::CAPACITY, 32);
+    ///
+    /// type Samples = RingBuffer<i16, 64>;
+    /// let mut scratch: [i16; Samples::CAPACITY] = [0; Samples::CAPACITY];
+    /// scratch[Samples::CAPACITY - 1] = 1;
+    /// assert_eq!(Samples::new().capacity(), Samples::CAPACITY);
+    /// ```
+    pub const CAPACITY: usize = SIZE;
+
     /// Creates an empty ring buffer.
     ///
     /// # Examples
     /// ```
     /// use fixed_collections::RingBuffer;
     ///
-    /// let ring: RingBuffer<16, u32> = RingBuffer::new();
+    /// let ring: RingBuffer<u32, 16> = RingBuffer::new();
     /// ```
     #[must_use]
     pub const fn new() -> Self {
         Self {
             head: 0,
             len: 0,
-            buffer: [const { None }; SIZE],
+            buffer: [const { MaybeUninit::uninit() }; SIZE],
+            #[cfg(feature = "generation")]
+            generation: 0,
         }
     }
 
@@ -32,10 +84,12 @@ impl<T, const SIZE: usize> RingBuffer<T, SIZE> {
     /// ```
     /// use fixed_collections::RingBuffer;
     /// 
-    /// let ring: RingBuffer<20, i16> = RingBuffer::new();
+    /// let mut ring: RingBuffer<i16, 20> = RingBuffer::new();
     /// assert_eq!(ring.len(), 0);
-    /// 
-    /// todo!("Create example demonstrating the length increasing when pushing front and back.")
+    ///
+    /// ring.push_back(1).unwrap();
+    /// ring.push_front(2).unwrap();
+    /// assert_eq!(ring.len(), 2);
     /// ```
     pub fn len(&self) -> usize {
         self.len
@@ -47,7 +101,7 @@ impl<T, const SIZE: usize> RingBuffer<T, SIZE> {
     /// ```
     /// use fixed_collections::RingBuffer;
     ///
-    /// let ring: RingBuffer<16, u32> = RingBuffer::new();
+    /// let ring: RingBuffer<u32, 16> = RingBuffer::new();
     /// assert!(ring.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
@@ -60,72 +114,530 @@ impl<T, const SIZE: usize> RingBuffer<T, SIZE> {
     /// ```
     /// use fixed_collections::RingBuffer;
     /// 
-    /// todo!("Create an example")
+    /// let mut ring: RingBuffer<u8, 2> = RingBuffer::new();
+    /// assert!(!ring.is_full());
+    ///
+    /// ring.push_back(1).unwrap();
+    /// ring.push_back(2).unwrap();
+    /// assert!(ring.is_full());
     /// ```
     pub fn is_full(&self) -> bool {
         self.len == SIZE
     }
 
+    /// Returns the maximum number of elements the ring buffer can hold, same
+    /// as [`RingBuffer::CAPACITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.capacity(), 4);
+    ///
+    /// ring.push_back(1).unwrap();
+    /// assert_eq!(ring.capacity(), 4);
+    /// ```
+    pub const fn capacity(&self) -> usize {
+        SIZE
+    }
+
+    /// Returns the number of elements that can still be pushed before the
+    /// ring buffer is full.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.remaining_capacity(), 4);
+    ///
+    /// ring.push_back(1).unwrap();
+    /// ring.push_front(0).unwrap();
+    /// assert_eq!(ring.remaining_capacity(), 2);
+    /// assert_eq!(ring.capacity(), 4);
+    ///
+    /// while ring.remaining_capacity() > 0 {
+    ///     ring.push_back(2).unwrap();
+    /// }
+    /// assert!(ring.is_full());
+    /// ```
+    pub fn remaining_capacity(&self) -> usize {
+        SIZE - self.len
+    }
+
+    /// Returns a counter that grows every time the ring buffer is modified,
+    /// such as by a push, pop, `clear` or `append`.
+    ///
+    /// Comparing two readings tells whether the ring buffer may have changed
+    /// in between, even if it ended up with the same length, which guards
+    /// against ABA problems with cached indices. Writes through mutable
+    /// element references, like [`RingBuffer::get_mut`], aren't counted.
+    /// The counter wraps around on overflow.
+    ///
+    /// Only available with the `generation` feature, as it makes every ring
+    /// buffer a `u64` larger.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// let start: u64 = ring.generation();
+    ///
+    /// // Pushing then popping leaves the same contents, but a new generation.
+    /// ring.push_back(1).unwrap();
+    /// ring.pop_front().unwrap();
+    /// assert!(ring.is_empty());
+    /// assert!(ring.generation() > start);
+    ///
+    /// let mut last: u64 = ring.generation();
+    /// let mut other: RingBuffer<u8, 4> = RingBuffer::from([5, 6, 7, 8]);
+    /// ring.push_front(0).unwrap();
+    /// assert!(ring.generation() > last);
+    /// last = ring.generation();
+    /// ring.append(&mut other).unwrap_err();
+    /// assert!(ring.generation() > last);
+    /// last = ring.generation();
+    /// ring.pop_back().unwrap();
+    /// assert!(ring.generation() > last);
+    /// last = ring.generation();
+    /// ring.clear();
+    /// assert!(ring.generation() > last);
+    ///
+    /// // Reading doesn't change it.
+    /// ring.push_back(1).unwrap();
+    /// let last: u64 = ring.generation();
+    /// assert_eq!(ring.len(), 1);
+    /// assert_eq!(ring.iter().count(), 1);
+    /// assert_eq!(ring.get(0), Some(&1));
+    /// assert_eq!(ring.generation(), last);
+    /// ```
+    #[cfg(feature = "generation")]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns a reference to the front element, or `None` if the ring buffer
+    /// is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.front(), None);
+    ///
+    /// ring.push_back(1).unwrap();
+    /// assert_eq!(ring.front(), Some(&1));
+    ///
+    /// ring.push_front(0).unwrap();
+    /// assert_eq!(ring.front(), Some(&0));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back element, or `None` if the ring buffer
+    /// is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.back(), None);
+    ///
+    /// ring.push_back(1).unwrap();
+    /// assert_eq!(ring.back(), Some(&1));
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_front(0).unwrap();
+    /// ring.push_back(2).unwrap();
+    /// assert_eq!(ring.front(), Some(&0));
+    /// assert_eq!(ring.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// Returns a mutable reference to the front element, or `None` if the
+    /// ring buffer is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.front_mut(), None);
+    ///
+    /// ring.push_back(1).unwrap();
+    /// *ring.front_mut().unwrap() = 5;
+    /// assert_eq!(ring.front(), Some(&5));
+    /// ```
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns a mutable reference to the back element, or `None` if the ring
+    /// buffer is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.back_mut(), None);
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_back(1).unwrap();
+    /// ring.push_front(0).unwrap();
+    /// *ring.back_mut().unwrap() = 7;
+    /// assert_eq!(ring.back(), Some(&7));
+    /// assert_eq!(ring.front(), Some(&0));
+    /// ```
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.checked_sub(1)?)
+    }
+
+    /// Returns a reference to the first element.
+    /// If the ring buffer is empty, we return Err(EmptyCollectionError)
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert!(ring.peek_front().is_err());
+    ///
+    /// ring.push_back(1).unwrap();
+    /// ring.push_front(0).unwrap();
+    /// assert_eq!(ring.peek_front().unwrap(), &0);
+    /// assert_eq!(ring.len(), 2);
+    /// ```
+    pub fn peek_front(&self) -> Result<&T, EmptyCollectionError> {
+        self.front().ok_or(EmptyCollectionError)
+    }
+
+    /// Returns a reference to the last element.
+    /// If the ring buffer is empty, we return Err(EmptyCollectionError)
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert!(ring.peek_back().is_err());
+    ///
+    /// ring.push_back(1).unwrap();
+    /// ring.push_front(0).unwrap();
+    /// assert_eq!(ring.peek_back().unwrap(), &1);
+    /// assert_eq!(ring.len(), 2);
+    /// ```
+    pub fn peek_back(&self) -> Result<&T, EmptyCollectionError> {
+        self.back().ok_or(EmptyCollectionError)
+    }
+
+    /// Returns a reference to the element `n` places after the front, or
+    /// `None` if `n >= len`. `peek_nth_front(0)` is the front element.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.peek_nth_front(0), Some(&1));
+    /// assert_eq!(ring.peek_nth_front(1), Some(&2));
+    /// assert_eq!(ring.peek_nth_front(3), Some(&4));
+    /// assert_eq!(ring.peek_nth_front(4), None);
+    /// ```
+    pub fn peek_nth_front(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    /// Returns a reference to the element `n` places before the back, or
+    /// `None` if `n >= len`. `peek_nth_back(0)` is the back element.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.peek_nth_back(0), Some(&4));
+    /// assert_eq!(ring.peek_nth_back(1), Some(&3));
+    /// assert_eq!(ring.peek_nth_back(3), Some(&1));
+    /// assert_eq!(ring.peek_nth_back(4), None);
+    /// assert_eq!(ring.peek_nth_back(usize::MAX), None);
+    /// ```
+    pub fn peek_nth_back(&self, n: usize) -> Option<&T> {
+        if n >= self.len {
+            return None;
+        }
+        self.get(self.len - 1 - n)
+    }
+
+    /// Returns a mutable reference to the first element.
+    /// If the ring buffer is empty, we return Err(EmptyCollectionError)
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{RingBuffer, error::EmptyCollectionError};
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert!(ring.first_mut().is_err());
+    ///
+    /// ring.push_back(1).unwrap();
+    /// ring.push_front(0).unwrap();
+    /// *ring.first_mut().unwrap() += 10;
+    ///
+    /// fn bump(ring: &mut RingBuffer<u8, 4>) -> Result<(), EmptyCollectionError> {
+    ///     *ring.first_mut()? += 1;
+    ///     Ok(())
+    /// }
+    /// bump(&mut ring).unwrap();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [11, 1]);
+    /// ```
+    pub fn first_mut(&mut self) -> Result<&mut T, EmptyCollectionError> {
+        self.front_mut().ok_or(EmptyCollectionError)
+    }
+
+    /// Returns a mutable reference to the last element.
+    /// If the ring buffer is empty, we return Err(EmptyCollectionError)
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{RingBuffer, error::EmptyCollectionError};
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert!(ring.last_mut().is_err());
+    ///
+    /// ring.push_back(1).unwrap();
+    /// ring.push_front(0).unwrap();
+    /// *ring.last_mut().unwrap() += 10;
+    ///
+    /// fn bump(ring: &mut RingBuffer<u8, 4>) -> Result<(), EmptyCollectionError> {
+    ///     *ring.last_mut()? += 1;
+    ///     Ok(())
+    /// }
+    /// bump(&mut ring).unwrap();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 12]);
+    /// ```
+    pub fn last_mut(&mut self) -> Result<&mut T, EmptyCollectionError> {
+        self.back_mut().ok_or(EmptyCollectionError)
+    }
+
+    /// Returns a reference to the element at logical `index`, where 0 is the
+    /// front and `len - 1` is the back. Returns `None` if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.get(0), Some(&2));
+    /// assert_eq!(ring.get(1), Some(&3));
+    /// assert_eq!(ring.get(2), Some(&4));
+    /// assert_eq!(ring.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: index < len, so the slot holds a live element.
+        Some(unsafe { self.buffer[self.physical_index(index)].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at logical `index`, where 0
+    /// is the front and `len - 1` is the back. Returns `None` if
+    /// `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// *ring.get_mut(3).unwrap() = 9;
+    /// assert_eq!(ring.get(3), Some(&9));
+    /// assert_eq!(ring.get_mut(4), None);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let index: usize = self.physical_index(index);
+        // SAFETY: index < len, so the slot holds a live element.
+        Some(unsafe { self.buffer[index].assume_init_mut() })
+    }
+
     /// Appends an element to the back of the ring buffer.
-    /// Returns `Err(FullCollectionError)` if the ring buffer is full.
+    /// Returns `Err(PushError(value))` if the ring buffer is full, handing the
+    /// value back.
     /// Returns the new length otherwise.
     /// 
     /// # Examples
     /// ```
-    /// todo!("Create an example")
+    /// use fixed_collections::{RingBuffer, error::PushError};
+    ///
+    /// let mut ring: RingBuffer<u8, 2> = RingBuffer::new();
+    /// assert_eq!(ring.push_back(1).unwrap().get(), 1);
+    /// assert_eq!(ring.push_back(2).unwrap().get(), 2);
+    /// assert_eq!(ring.push_back(3), Err(PushError(3)));
     /// ```
-    pub fn push_back(&mut self, value: T) -> Result<NonZero<usize>, FullCollectionError> {
+    pub fn push_back(&mut self, value: T) -> Result<NonZero<usize>, PushError<T>> {
+        // A zero capacity ring buffer is always full, so SIZE > 0 past this.
         if self.is_full() {
-            return Err(FullCollectionError);
+            return Err(PushError(value));
         }
-        let next_index: usize = (self.head + self.len) % SIZE; 
-        self.buffer[next_index] = Some(value);
+        let next_index: usize = self.physical_index(self.len);
+        self.buffer[next_index].write(value);
         self.len += 1;
+        self.bump_generation();
         // SAFETY: self.len must be > 0.
-        // We return Result<NonZero<usize, FullCollectionError> which should
-        // be size of usize (FullCollectionError get optimised to 0).
         Ok(NonZero::new(self.len).unwrap())
     }
 
     /// Prepends an element to the front of the ring buffer.
-    /// Returns `Err(FullCollectionError)` if the ring buffer is full.
+    /// Returns `Err(PushError(value))` if the ring buffer is full, handing the
+    /// value back.
     /// Returns the new length otherwise.
     /// 
     /// # Examples
     /// ```
-    /// todo!("Create an example")
+    /// use fixed_collections::{RingBuffer, error::PushError};
+    ///
+    /// let mut ring: RingBuffer<u8, 2> = RingBuffer::new();
+    /// assert_eq!(ring.push_front(1).unwrap().get(), 1);
+    /// assert_eq!(ring.push_front(2).unwrap().get(), 2);
+    /// assert_eq!(ring.push_front(3), Err(PushError(3)));
+    ///
+    /// // The rejected value can be routed elsewhere.
+    /// let mut overflow: RingBuffer<u8, 2> = RingBuffer::new();
+    /// if let Err(PushError(value)) = ring.push_front(4) {
+    ///     overflow.push_back(value).unwrap();
+    /// }
+    /// assert_eq!(overflow.front(), Some(&4));
     /// ```
-    pub fn push_front(&mut self, value: T) -> Result<NonZero<usize>, FullCollectionError> {
+    pub fn push_front(&mut self, value: T) -> Result<NonZero<usize>, PushError<T>> {
+        // A zero capacity ring buffer is always full, so SIZE > 0 past this.
         if self.is_full() {
-            return Err(FullCollectionError);
+            return Err(PushError(value));
         }
         let next_index: usize = if self.head == 0 {
             SIZE - 1
         } else { 
             self.head - 1
         };
-        self.buffer[next_index] = Some(value);
+        self.buffer[next_index].write(value);
+        self.head = next_index;
         self.len += 1;
+        self.bump_generation();
         // SAFETY: self.len must be > 0.
-        // We return Result<NonZero<usize, FullCollectionError> which should
-        // be size of usize (FullCollectionError gets optimised to 0).
         Ok(NonZero::new(self.len).unwrap())
     }
 
+    /// Appends an element to the back of the ring buffer, evicting the front
+    /// element if it's full. Returns the evicted element, or `None` if there
+    /// was room. A zero capacity ring buffer hands `value` straight back.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 3> = RingBuffer::new();
+    /// assert_eq!(ring.force_push_back(0), None);
+    /// assert_eq!(ring.force_push_back(1), None);
+    /// assert_eq!(ring.force_push_back(2), None);
+    ///
+    /// assert_eq!(ring.force_push_back(3), Some(0));
+    /// assert_eq!(ring.force_push_back(4), Some(1));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [2, 3, 4]);
+    /// ```
+    pub fn force_push_back(&mut self, value: T) -> Option<T> {
+        if SIZE == 0 {
+            return Some(value);
+        }
+        let evicted: Option<T> = if self.is_full() {
+            self.pop_front().ok()
+        } else {
+            None
+        };
+        // Can't fail, there's room for it now.
+        let _ = self.push_back(value);
+        evicted
+    }
+
+    /// Prepends an element to the front of the ring buffer, evicting the back
+    /// element if it's full. Returns the evicted element, or `None` if there
+    /// was room. A zero capacity ring buffer hands `value` straight back.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 3> = RingBuffer::new();
+    /// assert_eq!(ring.force_push_front(0), None);
+    /// assert_eq!(ring.force_push_front(1), None);
+    /// assert_eq!(ring.force_push_front(2), None);
+    ///
+    /// assert_eq!(ring.force_push_front(3), Some(0));
+    /// assert_eq!(ring.force_push_front(4), Some(1));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 3, 2]);
+    /// ```
+    pub fn force_push_front(&mut self, value: T) -> Option<T> {
+        if SIZE == 0 {
+            return Some(value);
+        }
+        let evicted: Option<T> = if self.is_full() {
+            self.pop_back().ok()
+        } else {
+            None
+        };
+        // Can't fail, there's room for it now.
+        let _ = self.push_front(value);
+        evicted
+    }
+
     /// Removes first element and returns it.
     /// If the ring buffer is empty, we return Err(EmptyCollectionError)
     /// 
     /// # Examples
     /// ```
-    /// todo!("Create an example")
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert!(ring.pop_front().is_err());
+    ///
+    /// ring.push_back(1).unwrap();
+    /// ring.push_back(2).unwrap();
+    /// assert_eq!(ring.pop_front().unwrap(), 1);
+    /// assert_eq!(ring.pop_front().unwrap(), 2);
+    /// assert!(ring.pop_front().is_err());
     /// ```
     pub fn pop_front(&mut self) -> Result<T, EmptyCollectionError> {
         if self.is_empty() {
             return Err(EmptyCollectionError);
         }
-        let value: T = self.buffer[self.head].take().unwrap();
-        self.head = (self.head + 1) % SIZE;
+        // SAFETY: The buffer isn't empty, so the head slot holds a live
+        // element. It's no longer counted as live once head moves past it.
+        let value: T = unsafe { self.buffer[self.head].assume_init_read() };
+        self.head = self.physical_index(1);
         self.len -= 1;
+        self.bump_generation();
         Ok(value)
     }
 
@@ -134,111 +646,4203 @@ impl<T, const SIZE: usize> RingBuffer<T, SIZE> {
     /// 
     /// # Examples
     /// ```
-    /// todo!("Create an example")
-    /// ```    
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert!(ring.pop_back().is_err());
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// for value in 0..4 {
+    ///     ring.push_back(value).unwrap();
+    /// }
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    /// ring.push_back(5).unwrap();
+    ///
+    /// assert_eq!(ring.pop_back().unwrap(), 5);
+    /// assert_eq!(ring.pop_back().unwrap(), 4);
+    /// assert_eq!(ring.pop_back().unwrap(), 3);
+    /// assert_eq!(ring.pop_back().unwrap(), 2);
+    /// assert!(ring.pop_back().is_err());
+    /// ```
+    ///
+    /// Mixing pushes at both ends:
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring.push_back(2).unwrap();
+    /// ring.push_front(1).unwrap();
+    /// ring.push_back(3).unwrap();
+    /// ring.push_front(0).unwrap();
+    ///
+    /// assert_eq!(ring.pop_back().unwrap(), 3);
+    /// assert_eq!(ring.pop_back().unwrap(), 2);
+    /// assert_eq!(ring.pop_front().unwrap(), 0);
+    /// assert_eq!(ring.pop_back().unwrap(), 1);
+    /// assert!(ring.is_empty());
+    /// ```
     pub fn pop_back(&mut self) -> Result<T, EmptyCollectionError> {
         if self.is_empty() {
             return Err(EmptyCollectionError)
         }
-        let index: usize = (self.head + self.len) % SIZE;
-        let value: T = self.buffer[index].take().unwrap();
+        let index: usize = self.physical_index(self.len - 1);
+        // SAFETY: The buffer isn't empty, so the back slot holds a live
+        // element. It's no longer counted as live once len shrinks.
+        let value: T = unsafe { self.buffer[index].assume_init_read() };
         self.len -= 1;
+        self.bump_generation();
         Ok(value)
     }
 
+    /// Removes and drops every element, leaving the ring buffer empty.
+    /// Only the occupied slots are touched, so each element is dropped
+    /// exactly once.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// ring.clear();
+    /// assert!(ring.is_empty());
+    /// assert_eq!(ring.remaining_capacity(), 4);
+    /// ```
     pub fn clear(&mut self) {
-        todo!("This should drop every element.");
+        while self.pop_front().is_ok() {}
+        self.head = 0;
+        self.bump_generation();
     }
 
-    /// Moves all elements of `other` into `self`, leaving `other` empty.
-    /// If the size of `self` is   
+    /// Moves all elements of `other` into the back of `self` in front-to-back
+    /// order, leaving `other` empty. Returns the number of elements moved.
+    ///
+    /// If `self` doesn't have enough remaining capacity, as many elements as
+    /// fit are moved and `Err(AppendError)` is returned, holding how many
+    /// were moved and how many remain. The elements that didn't fit stay at
+    /// the front of `other`, in their original order.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// let mut other: RingBuffer<u8, 2> = RingBuffer::from([1, 2]);
+    /// assert_eq!(ring.append(&mut other).unwrap(), 2);
+    /// assert!(other.is_empty());
+    ///
+    /// // Exactly fills the remaining capacity.
+    /// let mut other: RingBuffer<u8, 8> = RingBuffer::new();
+    /// other.push_back(3).unwrap();
+    /// other.push_back(4).unwrap();
+    /// assert_eq!(ring.append(&mut other).unwrap(), 2);
+    /// assert!(ring.is_full());
+    /// assert!(other.is_empty());
+    ///
+    /// assert_eq!(ring.pop_front().unwrap(), 1);
+    /// assert_eq!(ring.pop_front().unwrap(), 2);
+    /// assert_eq!(ring.pop_front().unwrap(), 3);
+    /// assert_eq!(ring.pop_front().unwrap(), 4);
+    /// ```
+    ///
+    /// Appending more than fits:
+    /// ```
+    /// use fixed_collections::{RingBuffer, error::AppendError};
+    ///
+    /// let mut ring: RingBuffer<u8, 3> = RingBuffer::new();
+    /// ring.push_back(1).unwrap();
+    /// let mut other: RingBuffer<u8, 4> = RingBuffer::from([2, 3, 4, 5]);
+    ///
+    /// assert_eq!(ring.append(&mut other), Err(AppendError { moved: 2, remaining: 2 }));
+    /// assert!(ring.is_full());
+    /// assert_eq!(other.len(), 2);
+    ///
+    /// // Nothing fits in a full ring buffer.
+    /// assert_eq!(ring.append(&mut other), Err(AppendError { moved: 0, remaining: 2 }));
+    /// assert_eq!(other.pop_front().unwrap(), 4);
+    /// assert_eq!(other.pop_front().unwrap(), 5);
+    /// ```
     pub fn append<const OTHER_SIZE: usize>(&mut self, other: &mut RingBuffer<T, OTHER_SIZE>)
-        -> Result<usize, ()> {
-        todo!("Iterate and take all into this ring buffer. Also create an error.")
+        -> Result<usize, AppendError> {
+        self.bump_generation();
+        other.bump_generation();
+        let mut moved: usize = 0;
+        while !other.is_empty() {
+            if self.is_full() {
+                return Err(AppendError { moved, remaining: other.len() });
+            }
+            // SAFETY: We unwrap here as other isn't empty.
+            let value: T = other.pop_front().unwrap();
+            // Can't fail, self isn't full.
+            let _ = self.push_back(value);
+            moved += 1;
+        }
+        Ok(moved)
     }
 
-    /// TODO: Make description, also check how to define this life time
-    pub fn into_cyclic_iter(&self) -> RingBufferCyclicIterator<'_, T, SIZE> {
-        RingBufferCyclicIterator { buffer: self, index: 0 }
+    /// Pushes the elements of `iter` to the back in order, returning the
+    /// number of elements pushed.
+    ///
+    /// Returns `Err(FullCollectionError)` if the iterator yields an element
+    /// once the ring buffer is full. That element is dropped, while the ones
+    /// pushed before it stay in the ring buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.try_extend([0, 1]).unwrap(), 2);
+    /// assert_eq!(ring.try_extend([2, 3]).unwrap(), 2);
+    /// assert!(ring.is_full());
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert!(ring.try_extend(0..5).is_err());
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3]);
+    /// ```
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<usize, FullCollectionError> {
+        let mut pushed: usize = 0;
+        for value in iter {
+            self.push_back(value)?;
+            pushed += 1;
+        }
+        Ok(pushed)
     }
-}
 
-impl<T> Default for RingBuffer<T, 16> {
-    /// Creates an empty RingBuffer of size 16.
-    fn default() -> Self {
-        Self::new()
+    /// Drops every element, then pushes the elements of `iter` to the back in
+    /// order, starting from the first slot. Returns the new length.
+    ///
+    /// Returns `Err(FullCollectionError)` if the iterator yields more than
+    /// `SIZE` elements. As with [`RingBuffer::try_extend`], the first `SIZE`
+    /// stay in the ring buffer and the one that didn't fit is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// assert_eq!(ring.reset_from([10, 11]).unwrap(), 2);
+    /// assert_eq!(ring, [10, 11]);
+    ///
+    /// assert!(ring.reset_from(10..15).is_err());
+    /// assert_eq!(ring, [10, 11, 12, 13]);
+    /// ```
+    pub fn reset_from<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<usize, FullCollectionError> {
+        self.clear();
+        self.try_extend(iter)
     }
-}
 
-impl<T, const SIZE: usize> Debug for RingBuffer<T, SIZE> where T: Debug {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("RingBuffer")
-            .field("head", &self.head)
+    /// Returns an iterator over references to the elements, from front to back.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// let mut expected: Vec<u8> = Vec::new();
+    /// for value in 0..4 {
+    ///     ring.push_back(value).unwrap();
+    ///     expected.push(value);
+    /// }
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// expected.remove(0);
+    /// ring.push_back(4).unwrap();
+    /// expected.push(4);
+    ///
+    /// assert_eq!(ring.iter().len(), 4);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), expected);
+    /// assert_eq!(ring.iter().rev().copied().collect::<Vec<u8>>(), [4, 3, 2, 1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back): (&[T], &[T]) = self.as_slices();
+        Iter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+
+    /// Returns a read-only view of the elements, which can be passed around
+    /// as a lightweight handle to them.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{RingBuffer, ring_buffer::RingView};
+    ///
+    /// fn total(view: RingView<'_, u8>) -> u8 {
+    ///     view.into_iter().sum()
+    /// }
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let view: RingView<'_, u8> = ring.view();
+    /// assert_eq!(view.len(), 4);
+    /// assert!(!view.is_empty());
+    /// assert_eq!(view[0], 1);
+    /// assert_eq!(view[3], 4);
+    /// assert_eq!(total(view), 10);
+    /// // Views are Copy, so this one is still usable.
+    /// assert_eq!(view.into_iter().copied().collect::<Vec<u8>>(), [1, 2, 3, 4]);
+    ///
+    /// // The ring buffer is usable again once the view is gone.
+    /// ring.pop_front().unwrap();
+    /// assert!(ring.view().into_iter().eq(&[2, 3, 4]));
+    /// ```
+    pub fn view(&self) -> RingView<'_, T> {
+        let (front, back): (&[T], &[T]) = self.as_slices();
+        RingView { front, back }
+    }
+
+    /// Splits the elements in two views at logical index `index`, without
+    /// moving them. The first view holds the elements `0..index`, the second
+    /// the elements `index..len`.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let (left, right) = ring.split_at(0);
+    /// assert!(left.is_empty());
+    /// assert!(right.into_iter().eq(&[1, 2, 3, 4]));
+    ///
+    /// // The second view starts in the wrapped part of the storage.
+    /// let (left, right) = ring.split_at(3);
+    /// assert!(left.into_iter().eq(&[1, 2, 3]));
+    /// assert!(right.into_iter().eq(&[4]));
+    ///
+    /// let (left, right) = ring.split_at(4);
+    /// assert!(left.into_iter().eq(&[1, 2, 3, 4]));
+    /// assert!(right.is_empty());
+    ///
+    /// let (left, right) = ring.split_at(2);
+    /// assert_eq!((left.len(), right.len()), (2, 2));
+    /// assert_eq!((left[1], right[0]), (2, 3));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring.push_back(0).unwrap();
+    /// ring.split_at(2);
+    /// ```
+    pub fn split_at(&self, index: usize) -> (RingView<'_, T>, RingView<'_, T>) {
+        if index > self.len {
+            panic!("`index` split index (is {index}) should be <= len (is {})", self.len);
+        }
+        let (front, back): (&[T], &[T]) = self.as_slices();
+        match index.checked_sub(front.len()) {
+            None => {
+                let (left, right): (&[T], &[T]) = front.split_at(index);
+                (RingView { front: left, back: &[] }, RingView { front: right, back })
+            }
+            Some(index) => {
+                let (left, right): (&[T], &[T]) = back.split_at(index);
+                (RingView { front, back: left }, RingView { front: right, back: &[] })
+            }
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements, from front
+    /// to back.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// for value in ring.iter_mut() {
+    ///     *value *= 10;
+    /// }
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [10, 20, 30, 40]);
+    ///
+    /// let mut iter = ring.iter_mut();
+    /// assert_eq!(iter.len(), 4);
+    /// *iter.next_back().unwrap() = 0;
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [10, 20, 30, 0]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back): (&mut [T], &mut [T]) = self.as_mut_slices();
+        IterMut {
+            front: front.iter_mut(),
+            back: back.iter_mut(),
+        }
+    }
+
+    /// Rotates the internal storage so the elements occupy the start of it in
+    /// logical order, and returns them as a single mutable slice. After this
+    /// call the ring buffer is no longer wrapped.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let slice: &mut [u8] = ring.make_contiguous();
+    /// assert_eq!(slice, [2, 3, 4]);
+    /// slice.sort_by(|a, b| b.cmp(a));
+    ///
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 3, 2]);
+    /// assert!(format!("{ring:?}").starts_with("RingBuffer { head: 0, len: 3"));
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.normalize();
+        // SAFETY: The live elements now occupy the first len slots.
+        unsafe { self.buffer[..self.len].assume_init_mut() }
+    }
+
+    /// Rotates the internal storage so the front is at the first slot and the
+    /// elements occupy the first `len` slots, without changing their logical
+    /// order. This is what [`RingBuffer::make_contiguous`] builds on.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// ring.normalize();
+    /// assert!(format!("{ring:?}").starts_with("RingBuffer { head: 0, len: 3"));
+    /// assert_eq!(ring.occupied_slots().map(|(index, _)| index).collect::<Vec<usize>>(), [0, 1, 2]);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [2, 3, 4]);
+    /// assert_eq!(ring.try_as_slice(), Some(&[2, 3, 4][..]));
+    /// ```
+    pub fn normalize(&mut self) {
+        // Moving MaybeUninit slots around is fine whether they're live or not.
+        self.buffer.rotate_left(self.head);
+        self.head = 0;
+        self.bump_generation();
+    }
+
+    /// Returns the elements as two slices in logical order: the first runs
+    /// from the front to the end of the storage, the second holds the
+    /// elements that wrapped around to its start. The second slice is empty
+    /// when the ring buffer isn't wrapped.
+    ///
+    /// The slices borrow the storage directly, no copying is involved.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.as_slices(), (&[][..], &[][..]));
+    ///
+    /// ring.push_back(0).unwrap();
+    /// ring.push_back(1).unwrap();
+    /// assert_eq!(ring.as_slices(), (&[0, 1][..], &[][..]));
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_front(3).unwrap();
+    /// assert_eq!(ring.as_slices(), (&[3][..], &[0, 1][..]));
+    ///
+    /// ring.push_back(2).unwrap();
+    /// assert!(ring.is_full());
+    /// let (front, back): (&[u8], &[u8]) = ring.as_slices();
+    /// assert_eq!([front, back].concat(), [3, 0, 1, 2]);
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back): (Range<usize>, Range<usize>) = self.slot_ranges();
+        // SAFETY: Both ranges only cover slots holding live elements.
+        unsafe { (self.buffer[front].assume_init_ref(), self.buffer[back].assume_init_ref()) }
+    }
+
+    /// Returns the elements as two mutable slices in logical order, split like
+    /// [`RingBuffer::as_slices`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let (front, back): (&mut [u8], &mut [u8]) = ring.as_mut_slices();
+    /// assert_eq!(front, [1, 2, 3]);
+    /// assert_eq!(back, [4]);
+    /// front[0] = 0;
+    /// back[0] = 5;
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 2, 3, 5]);
+    /// ```
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back): (Range<usize>, Range<usize>) = self.slot_ranges();
+        // The wrapped segment always lies before head, so splitting there
+        // hands out two disjoint mutable slices.
+        let (low, high): (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) = self.buffer.split_at_mut(self.head);
+        // SAFETY: Both ranges only cover slots holding live elements.
+        unsafe { (high[..front.len()].assume_init_mut(), low[back].assume_init_mut()) }
+    }
+
+    /// Returns the elements as a single slice in logical order, or `None` if
+    /// they wrap around the end of the buffer. Call
+    /// [`RingBuffer::make_contiguous`] first to always get a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// assert_eq!(ring.try_as_slice(), Some(&[1, 2, 3][..]));
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_back(4).unwrap();
+    /// assert_eq!(ring.try_as_slice(), None);
+    ///
+    /// ring.make_contiguous();
+    /// assert_eq!(ring.try_as_slice(), Some(&[1, 2, 3, 4][..]));
+    /// ```
+    pub fn try_as_slice(&self) -> Option<&[T]> {
+        match self.as_slices() {
+            (front, []) => Some(front),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the ring buffer contains an element equal to `value`.
+    /// Stops scanning at the first match.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert!(!ring.contains(&0));
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_back(1).unwrap();
+    /// ring.push_back(2).unwrap();
+    /// ring.push_front(0).unwrap();
+    /// assert!(ring.contains(&0));
+    /// assert!(ring.contains(&2));
+    /// assert!(!ring.contains(&3));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool where T: PartialEq {
+        self.iter().any(|element| element == value)
+    }
+
+    /// Rotates the logical order `n` places to the left, so the element at
+    /// logical index `n` becomes the front. `n` is taken modulo `len`, and
+    /// rotating an empty ring buffer does nothing.
+    ///
+    /// When the ring buffer is full this is O(1), only the head moves.
+    /// Otherwise the slots past the back are empty, so `min(n, len - n)`
+    /// elements are moved from one end to the other.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.rotate_left(1);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [1, 2, 3, 0]);
+    /// ring.rotate_left(6);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [3, 0, 1, 2]);
+    ///
+    /// // Wrapped and not full.
+    /// let mut ring: RingBuffer<u8, 5> = RingBuffer::new();
+    /// ring.extend([2, 3, 4]);
+    /// ring.push_front(1).unwrap();
+    /// ring.rotate_left(3);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 1, 2, 3]);
+    /// ring.rotate_left(1);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [1, 2, 3, 4]);
+    ///
+    /// let mut empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// empty.rotate_left(3);
+    /// assert!(empty.is_empty());
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.is_empty() {
+            return;
+        }
+        let n: usize = n % self.len;
+        if self.is_full() {
+            self.head = self.physical_index(n);
+            self.bump_generation();
+        } else if n <= self.len - n {
+            for _ in 0..n {
+                // SAFETY: We unwrap here as the buffer isn't empty, and the
+                // pop leaves room for the push.
+                let value: T = self.pop_front().unwrap();
+                let _ = self.push_back(value);
+            }
+        } else {
+            for _ in 0..self.len - n {
+                // SAFETY: We unwrap here as the buffer isn't empty, and the
+                // pop leaves room for the push.
+                let value: T = self.pop_back().unwrap();
+                let _ = self.push_front(value);
+            }
+        }
+    }
+
+    /// Rotates the logical order `n` places to the right, so the back `n`
+    /// elements move to the front. `n` is taken modulo `len`, and rotating an
+    /// empty ring buffer does nothing.
+    ///
+    /// Like [`RingBuffer::rotate_left`], this is O(1) when the ring buffer is
+    /// full and moves `min(n, len - n)` elements otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.rotate_right(1);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [3, 0, 1, 2]);
+    ///
+    /// // Wrapped and not full.
+    /// let mut ring: RingBuffer<u8, 5> = RingBuffer::new();
+    /// ring.extend([2, 3, 4]);
+    /// ring.push_front(1).unwrap();
+    /// ring.rotate_right(5);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 1, 2, 3]);
+    /// ring.rotate_right(4);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 1, 2, 3]);
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.is_empty() {
+            return;
+        }
+        self.rotate_left(self.len - n % self.len);
+    }
+
+    /// Shortens the ring buffer to `new_len` elements, dropping the elements
+    /// at logical indices `new_len..len`. Does nothing if `new_len >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.truncate(5);
+    /// assert_eq!(ring.len(), 4);
+    ///
+    /// ring.truncate(1);
+    /// assert_eq!(ring, [0]);
+    /// ```
+    pub fn truncate(&mut self, new_len: usize) {
+        while self.len > new_len {
+            // SAFETY: We unwrap here as len > new_len, so it isn't empty.
+            drop(self.pop_back().unwrap());
+        }
+    }
+
+    /// Resizes the ring buffer to `new_len` elements. Pushes elements returned
+    /// by `f` to the back if it grows, and drops the excess elements from the
+    /// back if it shrinks.
+    ///
+    /// Returns `Err(FullCollectionError)` if `new_len > SIZE`, in which case
+    /// the ring buffer is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::from([0, 1, 2, 3, 4, 5]);
+    /// ring.truncate(2);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_front(9).unwrap();
+    ///
+    /// let mut next: u8 = 10;
+    /// ring.resize_with(5, || {
+    ///     next += 1;
+    ///     next
+    /// }).unwrap();
+    /// assert_eq!(ring, [9, 0, 1, 11, 12]);
+    ///
+    /// assert!(ring.resize_with(7, || 0).is_err());
+    /// assert_eq!(ring, [9, 0, 1, 11, 12]);
+    ///
+    /// ring.resize_with(2, || unreachable!()).unwrap();
+    /// assert_eq!(ring, [9, 0]);
+    /// ```
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) -> Result<(), FullCollectionError> {
+        if new_len > SIZE {
+            return Err(FullCollectionError);
+        }
+        self.truncate(new_len);
+        while self.len < new_len {
+            // Can't fail, new_len is within the capacity.
+            let _ = self.push_back(f());
+        }
+        Ok(())
+    }
+
+    /// Resizes the ring buffer to `new_len` elements. Pushes clones of `value`
+    /// to the back if it grows, and drops the excess elements from the back if
+    /// it shrinks.
+    ///
+    /// Returns `Err(FullCollectionError)` if `new_len > SIZE`, in which case
+    /// the ring buffer is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    ///
+    /// ring.resize(4, 7).unwrap();
+    /// assert_eq!(ring, [2, 3, 7, 7]);
+    ///
+    /// assert!(ring.resize(5, 7).is_err());
+    /// assert_eq!(ring, [2, 3, 7, 7]);
+    ///
+    /// ring.resize(1, 7).unwrap();
+    /// assert_eq!(ring, [2]);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: T) -> Result<(), FullCollectionError> where T: Clone {
+        self.resize_with(new_len, || value.clone())
+    }
+
+    /// Swaps the elements at logical indices `i` and `j`. Equal indices leave
+    /// the ring buffer unchanged.
+    ///
+    /// # Panics
+    /// Panics if either index is `>= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// ring.swap(0, 2);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 3, 2]);
+    /// ring.swap(1, 1);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 3, 2]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.swap(0, 3);
+    /// ```
+    pub fn swap(&mut self, i: usize, j: usize) {
+        for index in [i, j] {
+            if index >= self.len {
+                panic!("index out of bounds: the len is {} but the index is {index}", self.len);
+            }
+        }
+        self.swap_slots(i, j);
+        self.bump_generation();
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, dropping the rest.
+    /// The kept elements stay in their front-to-back order.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::from([0, 1, 2, 3, 4, 5]);
+    /// ring.retain(|value| value % 3 != 0);
+    /// assert_eq!(ring, [1, 2, 4, 5]);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|value| f(value));
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, passing each a
+    /// mutable reference so it can be modified along the way. The others are
+    /// dropped, and the kept ones stay in logical order.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 5> = RingBuffer::from([0, 1, 2, 3, 4]);
+    /// // Drop the odd values and multiply the others by 10.
+    /// ring.retain_mut(|value| {
+    ///     *value *= 10;
+    ///     *value % 20 == 0
+    /// });
+    /// assert_eq!(ring, [0, 20, 40]);
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        // Cycle every element through the front, pushing back the ones kept.
+        for _ in 0..self.len {
+            // SAFETY: We unwrap here as the elements not visited yet are
+            // still in the buffer.
+            let mut value: T = self.pop_front().unwrap();
+            if f(&mut value) {
+                // Can't fail, the pop left room for it.
+                let _ = self.push_back(value);
+            }
+        }
+    }
+
+    /// Removes every element, returning them front to back through an
+    /// iterator. The ring buffer is empty as soon as this is called; elements
+    /// the iterator hasn't yielded are dropped along with it.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.drain().collect::<Vec<u8>>(), [1, 2, 3, 4]);
+    /// assert!(ring.is_empty());
+    ///
+    /// // The ring buffer can be reused afterwards.
+    /// ring.push_back(5).unwrap();
+    /// assert_eq!(ring.front(), Some(&5));
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        let head: usize = self.head;
+        let len: usize = self.len;
+        // The elements now belong to the iterator. If it's leaked they're
+        // leaked too, but the ring buffer stays valid.
+        self.head = 0;
+        self.len = 0;
+        self.bump_generation();
+        Drain { buffer: &mut self.buffer, head, len }
+    }
+
+    /// Inserts an element at logical `index`, shifting the elements after it
+    /// toward the back. Inserting at `len` is equivalent to
+    /// [`RingBuffer::push_back`]. Whichever side of `index` is shorter is the
+    /// one that gets shifted.
+    ///
+    /// Returns `Err(FullCollectionError)` if the ring buffer is full, dropping
+    /// `value`.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::new();
+    /// ring.extend([1, 3, 5]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_front(0).unwrap();
+    ///
+    /// ring.insert(2, 2).unwrap();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3, 5]);
+    /// ring.insert(4, 4).unwrap();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3, 4, 5]);
+    /// assert!(ring.insert(0, 9).is_err());
+    ///
+    /// ring.pop_front().unwrap();
+    /// ring.insert(0, 0).unwrap();
+    /// ring.pop_back().unwrap();
+    /// ring.insert(5, 5).unwrap();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring.push_back(0).unwrap();
+    /// let _ = ring.insert(2, 1);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), FullCollectionError> {
+        if index > self.len {
+            panic!("insertion index (is {index}) should be <= len (is {})", self.len);
+        }
+        if index < self.len - index {
+            self.push_front(value)?;
+            for i in 0..index {
+                self.swap_slots(i, i + 1);
+            }
+        } else {
+            self.push_back(value)?;
+            for i in (index..self.len - 1).rev() {
+                self.swap_slots(i, i + 1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes and returns the element at logical `index`, shifting the
+    /// elements on its shorter side to close the gap. Returns `None` if
+    /// `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::new();
+    /// ring.extend([1, 2, 3, 4, 5]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_front(0).unwrap();
+    ///
+    /// assert_eq!(ring.remove(2), Some(2));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 3, 4, 5]);
+    /// assert_eq!(ring.remove(3), Some(4));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 3, 5]);
+    /// assert_eq!(ring.remove(0), Some(0));
+    /// assert_eq!(ring.remove(2), Some(5));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [1, 3]);
+    /// assert_eq!(ring.remove(2), None);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        if index < self.len - 1 - index {
+            for i in (0..index).rev() {
+                self.swap_slots(i, i + 1);
+            }
+            self.pop_front().ok()
+        } else {
+            for i in index..self.len - 1 {
+                self.swap_slots(i, i + 1);
+            }
+            self.pop_back().ok()
+        }
+    }
+
+    /// Clones the elements into a `Vec`, in logical front-to-back order.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let vec: Vec<u8> = ring.to_vec();
+    /// let mut popped: Vec<u8> = Vec::new();
+    /// while let Ok(value) = ring.pop_front() {
+    ///     popped.push(value);
+    /// }
+    /// assert_eq!(vec, popped);
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        self.iter().cloned().collect()
+    }
+
+    /// Moves the elements into a `VecDeque`, in logical front-to-back order.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let deque: VecDeque<u8> = ring.into_vecdeque();
+    /// assert_eq!(deque, [1, 2, 3, 4]);
+    ///
+    /// // And back again.
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::try_from(deque).unwrap();
+    /// assert_eq!(ring, [1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vecdeque(self) -> VecDeque<T> {
+        let mut deque: VecDeque<T> = VecDeque::with_capacity(self.len);
+        deque.extend(self);
+        deque
+    }
+
+    /// Returns an iterator over every window of `size` consecutive elements,
+    /// in logical order. The windows overlap, and there are `len - size + 1`
+    /// of them, or none if `size > len`.
+    ///
+    /// As the elements may wrap around the end of the storage, a window can't
+    /// be a slice. Each one is yielded as an [`Iter`] over its elements
+    /// instead, which is just as cheap to create.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 5> = RingBuffer::from([0, 1, 2, 3, 4]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(5).unwrap();
+    ///
+    /// let windows: Vec<Vec<u8>> = ring.windows(3)
+    ///     .map(|window| window.copied().collect())
+    ///     .collect();
+    /// assert_eq!(windows, [[2, 3, 4], [3, 4, 5]]);
+    /// assert_eq!(ring.windows(1).len(), ring.len());
+    /// assert_eq!(ring.windows(4).len(), 1);
+    /// assert_eq!(ring.windows(5).len(), 0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.windows(0);
+    /// ```
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        if size == 0 {
+            panic!("window size must be non-zero");
+        }
+        let (front, back): (&[T], &[T]) = self.as_slices();
+        Windows { front, back, size, start: 0 }
+    }
+
+    /// Calls `f` with mutable references to the elements of every window of
+    /// `W` consecutive elements, from front to back.
+    ///
+    /// The windows overlap, so each one is only borrowed for its own call,
+    /// and a call sees the changes made by the previous ones.
+    ///
+    /// # Panics
+    /// Panics if `W` is 0 or `W > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 5> = RingBuffer::from([0, 1, 2, 3, 4]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(5).unwrap();
+    ///
+    /// let mut calls: usize = 0;
+    /// ring.for_each_window_mut(|[first, middle, last]: [&mut u8; 3]| {
+    ///     *last += *first + *middle;
+    ///     calls += 1;
+    /// });
+    /// assert_eq!(calls, 3);
+    /// // 3 + 1 + 2, then 4 + 2 + 6, then 5 + 6 + 12.
+    /// assert_eq!(ring, [1, 2, 6, 12, 23]);
+    ///
+    /// let mut ring: RingBuffer<u8, 3> = RingBuffer::from([1, 2, 3]);
+    /// ring.for_each_window_mut(|window: [&mut u8; 3]| window.into_iter().for_each(|value| *value *= 2));
+    /// assert_eq!(ring, [2, 4, 6]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.for_each_window_mut(|_: [&mut u8; 4]| {});
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.for_each_window_mut(|_: [&mut u8; 0]| {});
+    /// ```
+    pub fn for_each_window_mut<const W: usize, F: FnMut([&mut T; W])>(&mut self, mut f: F) {
+        if W == 0 {
+            panic!("window size must be non-zero");
+        }
+        if W > self.len {
+            panic!("window size (is {W}) should be <= len (is {})", self.len);
+        }
+        for start in 0..=self.len - W {
+            // SAFETY: We unwrap here as the indices are distinct and, with
+            // W <= len, all in bounds.
+            f(self.get_many_mut(core::array::from_fn(|offset| start + offset)).unwrap());
+        }
+    }
+
+    /// Returns an iterator over consecutive, non-overlapping chunks of `size`
+    /// elements, in logical order. The last chunk is shorter when `size`
+    /// doesn't divide the length.
+    ///
+    /// As with [`RingBuffer::windows`], a chunk can wrap around the end of the
+    /// storage, so each one is yielded as an [`Iter`] over its elements.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::from([0, 1, 2, 3, 4, 5]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(6).unwrap();
+    /// ring.push_back(7).unwrap();
+    ///
+    /// let chunks: Vec<Vec<u8>> = ring.chunks(2)
+    ///     .map(|chunk| chunk.copied().collect())
+    ///     .collect();
+    /// assert_eq!(chunks, [[2, 3], [4, 5], [6, 7]]);
+    ///
+    /// let chunks: Vec<Vec<u8>> = ring.chunks(4)
+    ///     .map(|chunk| chunk.copied().collect())
+    ///     .collect();
+    /// assert_eq!(chunks, [vec![2, 3, 4, 5], vec![6, 7]]);
+    /// assert_eq!(ring.chunks(4).len(), 2);
+    /// assert_eq!(ring.chunks(5).len(), 2);
+    /// assert_eq!(ring.chunks(6).len(), 1);
+    ///
+    /// let empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(empty.chunks(2).count(), 0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.chunks(0);
+    /// ```
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        if size == 0 {
+            panic!("chunk size must be non-zero");
+        }
+        let (front, back): (&[T], &[T]) = self.as_slices();
+        Chunks { front, back, size, start: 0 }
+    }
+
+    /// Returns an iterator over non-overlapping chunks of exactly `size`
+    /// elements, from front to back. Each chunk is itself an iterator over its
+    /// elements.
+    /// The last `len % size` elements, which don't make up a whole chunk, are
+    /// skipped, but can be accessed through [`ChunksExact::remainder`].
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::from([0, 1, 2, 3, 4, 5]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(6).unwrap();
+    /// ring.push_back(7).unwrap();
+    ///
+    /// let chunks: Vec<Vec<u8>> = ring.chunks_exact(3)
+    ///     .map(|chunk| chunk.copied().collect())
+    ///     .collect();
+    /// assert_eq!(chunks, [[2, 3, 4], [5, 6, 7]]);
+    /// assert_eq!(ring.chunks_exact(3).remainder().count(), 0);
+    ///
+    /// let mut iter = ring.chunks_exact(4);
+    /// assert_eq!(iter.len(), 1);
+    /// assert_eq!(iter.next().unwrap().copied().collect::<Vec<u8>>(), [2, 3, 4, 5]);
+    /// assert!(iter.next().is_none());
+    /// assert_eq!(iter.remainder().copied().collect::<Vec<u8>>(), [6, 7]);
+    ///
+    /// let iter = ring.chunks_exact(7);
+    /// assert_eq!(iter.len(), 0);
+    /// assert_eq!(iter.remainder().len(), 6);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.chunks_exact(0);
+    /// ```
+    pub fn chunks_exact(&self, size: usize) -> ChunksExact<'_, T> {
+        if size == 0 {
+            panic!("chunk size must be non-zero");
+        }
+        let (front, back): (&[T], &[T]) = self.as_slices();
+        let end: usize = self.len - self.len % size;
+        ChunksExact { front, back, size, start: 0, end }
+    }
+
+    /// Pushes clones of `value` to the back until the ring buffer is full.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring.fill(7);
+    /// assert!(ring.is_full());
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [7, 7, 7, 7]);
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring.push_back(1).unwrap();
+    /// ring.push_front(0).unwrap();
+    /// ring.fill(9);
+    /// assert_eq!(ring.len(), 4);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 9, 9]);
+    /// ```
+    pub fn fill(&mut self, value: T) where T: Clone {
+        self.fill_with(|| value.clone());
+    }
+
+    /// Pushes values returned by `f` to the back until the ring buffer is
+    /// full, calling it once per free slot.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u32, 5> = RingBuffer::new();
+    /// ring.push_back(0).unwrap();
+    /// ring.push_back(1).unwrap();
+    ///
+    /// let mut next: u32 = 10;
+    /// ring.fill_with(|| {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(ring.len(), 5);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u32>>(), [0, 1, 11, 12, 13]);
+    ///
+    /// // A full ring buffer doesn't call f at all.
+    /// ring.fill_with(|| unreachable!());
+    /// ```
+    pub fn fill_with<F: FnMut() -> T>(&mut self, mut f: F) {
+        while !self.is_full() {
+            // Can't fail, self isn't full.
+            let _ = self.push_back(f());
+        }
+    }
+
+    /// Removes the first element and returns it if `f` returns `true` for it.
+    /// Returns `None` otherwise, leaving the ring buffer unchanged. `f` isn't
+    /// called when the ring buffer is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.pop_front_if(|value| *value > 1), None);
+    /// assert_eq!(ring.len(), 4);
+    /// assert_eq!(ring.pop_front_if(|value| *value == 1), Some(1));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [2, 3, 4]);
+    ///
+    /// let mut empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(empty.pop_front_if(|_| unreachable!()), None);
+    /// ```
+    pub fn pop_front_if<F: FnOnce(&T) -> bool>(&mut self, f: F) -> Option<T> {
+        if f(self.front()?) {
+            self.pop_front().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Removes the last element and returns it if `f` returns `true` for it.
+    /// Returns `None` otherwise, leaving the ring buffer unchanged. `f` isn't
+    /// called when the ring buffer is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.pop_back_if(|value| *value < 4), None);
+    /// assert_eq!(ring.len(), 4);
+    /// assert_eq!(ring.pop_back_if(|value| *value == 4), Some(4));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [1, 2, 3]);
+    ///
+    /// let mut empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(empty.pop_back_if(|_| unreachable!()), None);
+    /// ```
+    pub fn pop_back_if<F: FnOnce(&T) -> bool>(&mut self, f: F) -> Option<T> {
+        if f(self.back()?) {
+            self.pop_back().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the logical index of the first element, front to back, for
+    /// which `f` returns `true`, or `None` if there's no such element.
+    /// The index can be passed to [`RingBuffer::get`] or [`RingBuffer::remove`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// // 4 is stored in the first physical slot, but is last logically.
+    /// let index: usize = ring.position(|value| *value == 4).unwrap();
+    /// assert_eq!(index, 3);
+    /// assert_eq!(ring.get(index), Some(&4));
+    /// assert_eq!(ring.position(|value| *value % 2 == 0), Some(1));
+    /// assert_eq!(ring.position(|value| *value > 4), None);
+    ///
+    /// assert_eq!(ring.remove(index), Some(4));
+    /// ```
+    pub fn position<F: FnMut(&T) -> bool>(&self, f: F) -> Option<usize> {
+        self.iter().position(f)
+    }
+
+    /// Returns a reference to the first element, front to back, for which `f`
+    /// returns `true`, or `None` if there's no such element.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.find(|value| *value > 2), Some(&3));
+    /// assert_eq!(ring.find(|value| *value == 0), None);
+    /// ```
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut f: F) -> Option<&T> {
+        self.iter().find(|value| f(value))
+    }
+
+    /// Returns a reference to the first element, front to back, whose key
+    /// extracted by `key_fn` equals `key`, or `None` if there's no such
+    /// element.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// // Doesn't need to implement PartialEq itself.
+    /// #[derive(Debug)]
+    /// struct Record {
+    ///     id: u32,
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let mut ring: RingBuffer<Record, 3> = RingBuffer::new();
+    /// ring.push_back(Record { id: 2, name: "second" }).unwrap();
+    /// ring.push_back(Record { id: 3, name: "third" }).unwrap();
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_front(Record { id: 1, name: "first" }).unwrap();
+    ///
+    /// assert_eq!(ring.find_by_key(&1, |record| record.id).unwrap().name, "first");
+    /// assert_eq!(ring.find_by_key(&3, |record| record.id).unwrap().name, "third");
+    /// assert!(ring.find_by_key(&4, |record| record.id).is_none());
+    /// assert_eq!(ring.find_by_key(&"second", |record| record.name).unwrap().id, 2);
+    /// ```
+    pub fn find_by_key<K: PartialEq, F: FnMut(&T) -> K>(&self, key: &K, mut key_fn: F) -> Option<&T> {
+        self.iter().find(|value| key_fn(value) == *key)
+    }
+
+    /// Returns a reference to the largest element, or `None` if the ring
+    /// buffer is empty.
+    /// Like [`Iterator::max`], the last of several equally large elements is
+    /// returned.
+    ///
+    /// As the ring buffer is itself [`Ord`] when `T` is, `ring.max()` on an
+    /// owned ring buffer resolves to [`Ord::max`] instead, so call this
+    /// through a reference or as `RingBuffer::max(&ring)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::ptr;
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 3, 1, 2]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(3).unwrap();
+    ///
+    /// assert_eq!(RingBuffer::max(&ring), Some(&3));
+    /// assert!(ptr::eq(RingBuffer::max(&ring).unwrap(), &ring[3]));
+    ///
+    /// // Through a reference, method call syntax works too.
+    /// let values: &RingBuffer<u8, 4> = &ring;
+    /// assert_eq!(values.max(), Some(&3));
+    ///
+    /// let empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(RingBuffer::max(&empty), None);
+    /// ```
+    pub fn max(&self) -> Option<&T> where T: Ord {
+        self.iter().max()
+    }
+
+    /// Returns a reference to the smallest element, or `None` if the ring
+    /// buffer is empty.
+    /// Like [`Iterator::min`], the first of several equally small elements is
+    /// returned.
+    ///
+    /// As the ring buffer is itself [`Ord`] when `T` is, `ring.min()` on an
+    /// owned ring buffer resolves to [`Ord::min`] instead, so call this
+    /// through a reference or as `RingBuffer::min(&ring)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::ptr;
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(1).unwrap();
+    ///
+    /// assert_eq!(RingBuffer::min(&ring), Some(&1));
+    /// assert!(ptr::eq(RingBuffer::min(&ring).unwrap(), &ring[0]));
+    ///
+    /// // Through a reference, method call syntax works too.
+    /// let values: &RingBuffer<u8, 4> = &ring;
+    /// assert_eq!(values.min(), Some(&1));
+    ///
+    /// let empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(RingBuffer::min(&empty), None);
+    /// ```
+    pub fn min(&self) -> Option<&T> where T: Ord {
+        self.iter().min()
+    }
+
+    /// Returns a reference to the element for which `f` returns the largest
+    /// key, or `None` if the ring buffer is empty.
+    /// Like [`Iterator::max_by_key`], the last of several elements with
+    /// equally large keys is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<(u8, char), 4> = RingBuffer::from([(9, 'x'), (2, 'a'), (1, 'b'), (2, 'c')]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back((0, 'd')).unwrap();
+    ///
+    /// assert_eq!(ring.max_by_key(|(key, _)| *key), Some(&(2, 'c')));
+    ///
+    /// let empty: RingBuffer<(u8, char), 4> = RingBuffer::new();
+    /// assert_eq!(empty.max_by_key(|(key, _)| *key), None);
+    /// ```
+    pub fn max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+        self.iter().max_by_key(|value| f(value))
+    }
+
+    /// Returns a reference to the element for which `f` returns the smallest
+    /// key, or `None` if the ring buffer is empty.
+    /// Like [`Iterator::min_by_key`], the first of several elements with
+    /// equally small keys is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<(u8, char), 4> = RingBuffer::from([(0, 'x'), (2, 'a'), (1, 'b'), (3, 'c')]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back((1, 'd')).unwrap();
+    ///
+    /// assert_eq!(ring.min_by_key(|(key, _)| *key), Some(&(1, 'b')));
+    ///
+    /// let empty: RingBuffer<(u8, char), 4> = RingBuffer::new();
+    /// assert_eq!(empty.min_by_key(|(key, _)| *key), None);
+    /// ```
+    pub fn min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+        self.iter().min_by_key(|value| f(value))
+    }
+
+    /// Folds every element into an accumulator, from front to back, by
+    /// applying `f` to the accumulator and each element in turn, starting from
+    /// `init`.
+    /// Returns the final accumulator.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.fold(0u32, |sum, value| sum + u32::from(*value)), 10);
+    /// // Folding follows the logical order.
+    /// assert_eq!(ring.fold(0u32, |digits, value| digits * 10 + u32::from(*value)), 1234);
+    ///
+    /// let empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(empty.fold(7, |_, _| 0), 7);
+    /// ```
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Calls `f` on every element, from front to back.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let mut seen: Vec<u8> = Vec::new();
+    /// ring.for_each(|value| seen.push(*value));
+    /// assert_eq!(seen, [1, 2, 3, 4]);
+    /// ```
+    pub fn for_each<F: FnMut(&T)>(&self, f: F) {
+        self.iter().for_each(f);
+    }
+
+    /// Splits the ring buffer in two at logical index `at`. Returns a new ring
+    /// buffer holding the elements `at..len`, starting at its first slot,
+    /// while `self` keeps the elements `0..at`.
+    ///
+    /// # Panics
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let back: RingBuffer<u8, 4> = ring.split_off(1);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [1]);
+    /// assert_eq!(back.iter().copied().collect::<Vec<u8>>(), [2, 3, 4]);
+    /// assert_eq!(back.as_slices(), (&[2, 3, 4][..], &[][..]));
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let all: RingBuffer<u8, 4> = ring.split_off(0);
+    /// assert!(ring.is_empty());
+    /// assert_eq!(all.len(), 4);
+    /// assert_eq!(all.iter().copied().collect::<Vec<u8>>(), [1, 2, 3, 4]);
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = all;
+    /// let none: RingBuffer<u8, 4> = ring.split_off(4);
+    /// assert!(none.is_empty());
+    /// assert_eq!(ring.len(), 4);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring.push_back(0).unwrap();
+    /// ring.split_off(2);
+    /// ```
+    #[must_use]
+    pub fn split_off(&mut self, at: usize) -> RingBuffer<T, SIZE> {
+        if at > self.len {
+            panic!("`at` split index (is {at}) should be <= len (is {})", self.len);
+        }
+        let mut other: RingBuffer<T, SIZE> = RingBuffer::new();
+        for index in at..self.len {
+            let slot: usize = self.physical_index(index);
+            // SAFETY: index < len, so the slot holds a live element. It's no
+            // longer counted as live once len shrinks to at.
+            let value: T = unsafe { self.buffer[slot].assume_init_read() };
+            // Can't fail, other has room for every element of self.
+            let _ = other.push_back(value);
+        }
+        self.len = at;
+        self.bump_generation();
+        other
+    }
+
+    /// Consumes the ring buffer, returning a new one holding the result of
+    /// applying `f` to each element in logical order. The new ring buffer has
+    /// the same length and starts at its first slot.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<i32, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(-4).unwrap();
+    ///
+    /// let strings: RingBuffer<String, 4> = ring.map(|value| value.to_string());
+    /// assert_eq!(strings.len(), 4);
+    /// assert_eq!(strings.iter().collect::<Vec<&String>>(), ["1", "2", "3", "-4"]);
+    ///
+    /// let empty: RingBuffer<i32, 4> = RingBuffer::new();
+    /// assert!(empty.map(|value| value.to_string()).is_empty());
+    /// ```
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> RingBuffer<U, SIZE> {
+        let mut mapped: RingBuffer<U, SIZE> = RingBuffer::new();
+        for value in self {
+            // Can't fail, mapped has room for every element of self.
+            let _ = mapped.push_back(f(value));
+        }
+        mapped
+    }
+
+    /// Returns mutable references to the elements at each of the logical
+    /// `indices` at once, in the same order.
+    /// Returns `None` if any index is `>= len`, or if any two indices are
+    /// equal, as the references would alias.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// // 2 is stored in the last physical slot and 4 in the first.
+    /// let [last, first]: [&mut u8; 2] = ring.get_many_mut([2, 0]).unwrap();
+    /// core::mem::swap(last, first);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 3, 2]);
+    ///
+    /// assert!(ring.get_many_mut([0, 2, 0]).is_none());
+    /// assert!(ring.get_many_mut([0, 3]).is_none());
+    /// assert!(ring.get_many_mut([]).is_some());
+    /// ```
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        for (position, index) in indices.iter().enumerate() {
+            if *index >= self.len || indices[..position].contains(index) {
+                return None;
+            }
+        }
+        let indices: [usize; N] = indices.map(|index| self.physical_index(index));
+        let slots: *mut MaybeUninit<T> = self.buffer.as_mut_ptr();
+        // SAFETY: The indices are in bounds and distinct, and logical indices
+        // map to distinct physical slots, so the references are to distinct
+        // live elements and don't alias.
+        Some(indices.map(|index| unsafe { (*slots.add(index)).assume_init_mut() }))
+    }
+
+    /// Binary searches a ring buffer sorted in logical order for `value`.
+    ///
+    /// Returns `Ok(index)` with the logical index of a matching element, or
+    /// `Err(index)` with the logical index `value` could be inserted at to keep
+    /// the ring buffer sorted. As with [`<[T]>::binary_search`], any of
+    /// several matching elements may be found, and the result is unspecified
+    /// if the ring buffer isn't sorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::from([0, 0, 1, 3, 5, 8]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(13).unwrap();
+    /// ring.push_back(21).unwrap();
+    ///
+    /// assert_eq!(ring.binary_search(&1), Ok(0));
+    /// assert_eq!(ring.binary_search(&13), Ok(4));
+    /// assert_eq!(ring.binary_search(&21), Ok(5));
+    /// assert_eq!(ring.binary_search(&0), Err(0));
+    /// assert_eq!(ring.binary_search(&9), Err(4));
+    /// assert_eq!(ring.binary_search(&34), Err(6));
+    ///
+    /// let empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(empty.binary_search(&1), Err(0));
+    /// ```
+    pub fn binary_search(&self, value: &T) -> Result<usize, usize> where T: Ord {
+        self.binary_search_by(|element| element.cmp(value))
+    }
+
+    /// Binary searches a ring buffer sorted in logical order with a comparator
+    /// function, which returns whether an element is less than, equal to or
+    /// greater than the target.
+    ///
+    /// Returns `Ok(index)` with the logical index of a matching element, or
+    /// `Err(index)` with the logical index the target could be inserted at,
+    /// just like [`RingBuffer::binary_search`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<(u8, char), 4> = RingBuffer::new();
+    /// ring.push_back((3, 'c')).unwrap();
+    /// ring.push_back((5, 'e')).unwrap();
+    /// ring.push_front((2, 'b')).unwrap();
+    /// ring.push_front((1, 'a')).unwrap();
+    ///
+    /// assert_eq!(ring.binary_search_by(|(key, _)| key.cmp(&3)), Ok(2));
+    /// assert_eq!(ring.binary_search_by(|(key, _)| key.cmp(&4)), Err(3));
+    /// ```
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> Result<usize, usize> {
+        let mut left: usize = 0;
+        let mut right: usize = self.len;
+        while left < right {
+            let middle: usize = left + (right - left) / 2;
+            match f(&self[middle]) {
+                Ordering::Less => left = middle + 1,
+                Ordering::Greater => right = middle,
+                Ordering::Equal => return Ok(middle),
+            }
+        }
+        Err(left)
+    }
+
+    /// Returns `true` if the elements are sorted in ascending logical order.
+    /// Empty and single-element ring buffers are always sorted.
+    ///
+    /// As with [`<[T]>::is_sorted`], incomparable neighbours, like a NaN and a
+    /// number, make the elements unsorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// assert!(ring.is_sorted());
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    /// assert!(ring.is_sorted());
+    ///
+    /// ring.swap(0, 3);
+    /// assert!(!ring.is_sorted());
+    ///
+    /// assert!(RingBuffer::<u8, 4>::new().is_sorted());
+    /// assert!(RingBuffer::from([7]).is_sorted());
+    /// assert!(!RingBuffer::from([1.0, f32::NAN]).is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool where T: PartialOrd {
+        self.iter().is_sorted()
+    }
+
+    /// Returns `true` if every pair of neighbouring elements, in logical order,
+    /// is in order according to `f`. `f(a, b)` returns whether `a` may come
+    /// before `b`.
+    /// Empty and single-element ring buffers are always sorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([9, 3, 2, 1]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(0).unwrap();
+    ///
+    /// assert!(ring.is_sorted_by(|a, b| a >= b));
+    /// assert!(!ring.is_sorted_by(|a, b| a <= b));
+    ///
+    /// ring.swap(1, 2);
+    /// assert!(!ring.is_sorted_by(|a, b| a >= b));
+    ///
+    /// let single: RingBuffer<u8, 1> = RingBuffer::from([7]);
+    /// assert!(single.is_sorted_by(|_, _| false));
+    /// ```
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, mut f: F) -> bool {
+        self.iter().is_sorted_by(|a, b| f(a, b))
+    }
+
+    /// Consumes the ring buffer, moving its elements in logical order into a
+    /// new one with a capacity of `NEW_SIZE`, starting at its first slot.
+    ///
+    /// Returns `Err(FullCollectionError)` if `len > NEW_SIZE`, in which case
+    /// the elements are dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let mut larger: RingBuffer<u8, 8> = ring.resize_to().unwrap();
+    /// assert_eq!(larger.iter().copied().collect::<Vec<u8>>(), [1, 2, 3, 4]);
+    /// assert_eq!(larger.remaining_capacity(), 4);
+    ///
+    /// larger.pop_back().unwrap();
+    /// let smaller: RingBuffer<u8, 3> = larger.resize_to().unwrap();
+    /// assert_eq!(smaller.iter().copied().collect::<Vec<u8>>(), [1, 2, 3]);
+    /// assert!(smaller.is_full());
+    /// ```
+    pub fn resize_to<const NEW_SIZE: usize>(self) -> Result<RingBuffer<T, NEW_SIZE>, FullCollectionError> {
+        if self.len > NEW_SIZE {
+            return Err(FullCollectionError);
+        }
+        let mut resized: RingBuffer<T, NEW_SIZE> = RingBuffer::new();
+        for value in self {
+            // Can't fail, resized has room for every element of self.
+            let _ = resized.push_back(value);
+        }
+        Ok(resized)
+    }
+
+    /// Converts a full ring buffer into an array of its elements, from front
+    /// to back.
+    ///
+    /// Returns `Err(self)` if the ring buffer isn't full, handing it back
+    /// untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    /// assert_eq!(ring.try_into_array().unwrap(), [1, 2, 3, 4]);
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// let ring: RingBuffer<u8, 4> = ring.try_into_array().unwrap_err();
+    /// assert_eq!(ring, [1, 2, 3]);
+    /// ```
+    pub fn try_into_array(mut self) -> Result<[T; SIZE], Self> {
+        if self.len != SIZE {
+            return Err(self);
+        }
+        self.normalize();
+        // The elements are moved out, so Drop mustn't see any of them.
+        self.len = 0;
+        let buffer: [MaybeUninit<T>; SIZE] = mem::replace(&mut self.buffer, [const { MaybeUninit::uninit() }; SIZE]);
+        // SAFETY: The ring buffer was full, so every slot was initialized.
+        Ok(buffer.map(|slot| unsafe { slot.assume_init() }))
+    }
+
+    /// Copies every element of `slice` to the back in order, returning the
+    /// number of elements copied.
+    ///
+    /// Returns `Err(FullCollectionError)` if the whole slice doesn't fit in the
+    /// remaining capacity, in which case nothing is copied.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::new();
+    /// assert_eq!(ring.extend_from_slice(&[0, 1]).unwrap(), 2);
+    /// assert_eq!(ring.extend_from_slice(&[]).unwrap(), 0);
+    ///
+    /// // Doesn't fit, so nothing is copied.
+    /// assert!(ring.extend_from_slice(&[2, 3, 4, 5, 6]).is_err());
+    /// assert_eq!(ring.len(), 2);
+    ///
+    /// // Exactly fills the remaining capacity.
+    /// assert_eq!(ring.extend_from_slice(&[2, 3, 4, 5]).unwrap(), 4);
+    /// assert!(ring.is_full());
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn extend_from_slice(&mut self, slice: &[T]) -> Result<usize, FullCollectionError> where T: Copy {
+        if slice.len() > self.remaining_capacity() {
+            return Err(FullCollectionError);
+        }
+        for value in slice {
+            // Can't fail, there's room for the whole slice.
+            let _ = self.push_back(*value);
+        }
+        Ok(slice.len())
+    }
+
+    /// Creates a ring buffer holding the elements of `array` in order,
+    /// starting at its first slot. The array may be shorter than the
+    /// capacity, leaving the remaining slots free.
+    ///
+    /// `N <= SIZE` is checked at compile time.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from_array([0, 1]);
+    /// assert_eq!(ring.len(), 2);
+    /// assert_eq!(ring.as_slices(), (&[0, 1][..], &[][..]));
+    /// ring.push_back(2).unwrap();
+    ///
+    /// let full: RingBuffer<u8, 4> = RingBuffer::from_array([0, 1, 2, 3]);
+    /// assert!(full.is_full());
+    /// assert_eq!(full.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring: RingBuffer<u8, 2> = RingBuffer::from_array([0, 1, 2]);
+    /// ```
+    pub fn from_array<const N: usize>(array: [T; N]) -> Self {
+        const { assert!(N <= SIZE, "array length N must be <= SIZE") };
+        let mut ring: Self = Self::new();
+        for value in array {
+            // Can't fail, N <= SIZE.
+            let _ = ring.push_back(value);
+        }
+        ring
+    }
+
+    /// Removes the element at logical `index` and returns it, replacing it
+    /// with the last element. This is O(1), but doesn't preserve the order of
+    /// the remaining elements.
+    /// Returns `None` if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.swap_remove_back(0), Some(1));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 2, 3]);
+    /// assert_eq!(ring.swap_remove_back(2), Some(3));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 2]);
+    /// assert_eq!(ring.swap_remove_back(2), None);
+    /// ```
+    pub fn swap_remove_back(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        self.swap_slots(index, self.len - 1);
+        self.pop_back().ok()
+    }
+
+    /// Removes the element at logical `index` and returns it, replacing it
+    /// with the first element. This is O(1), but doesn't preserve the order
+    /// of the remaining elements.
+    /// Returns `None` if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.swap_remove_front(3), Some(4));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [2, 3, 1]);
+    /// assert_eq!(ring.swap_remove_front(0), Some(2));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [3, 1]);
+    /// assert_eq!(ring.swap_remove_front(2), None);
+    /// ```
+    pub fn swap_remove_front(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        self.swap_slots(index, 0);
+        self.pop_front().ok()
+    }
+
+    /// Removes consecutive equal elements in logical order, keeping the first
+    /// of each run. The removed elements are dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 8> = RingBuffer::from([0, 0, 1, 1, 1, 2, 3, 3]);
+    /// // Wrap the live elements around the end of the buffer, with a run of 3s
+    /// // across the boundary.
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(3).unwrap();
+    ///
+    /// ring.dedup();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3]);
+    /// ```
+    pub fn dedup(&mut self) where T: PartialEq {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements in logical order that map to the same
+    /// key, keeping the first of each run. The removed elements are dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<i32, 6> = RingBuffer::from([2, -2, 3, 4, -4, 4]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(-5).unwrap();
+    ///
+    /// ring.dedup_by_key(|value| value.abs());
+    /// assert_eq!(ring.iter().copied().collect::<Vec<i32>>(), [-2, 3, 4, -5]);
+    /// ```
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements in logical order for which `same_bucket`
+    /// returns `true`, keeping the first of each run. The removed elements
+    /// are dropped.
+    ///
+    /// `same_bucket(a, b)` is passed each element `a` and the last element `b`
+    /// kept before it, and `a` is removed if it returns `true`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<&str, 6> = RingBuffer::from(["x", "a", "A", "b", "B", "b"]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back("c").unwrap();
+    ///
+    /// ring.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(ring.iter().copied().collect::<Vec<&str>>(), ["a", "b", "c"]);
+    ///
+    /// let mut empty: RingBuffer<&str, 4> = RingBuffer::new();
+    /// empty.dedup_by(|_, _| unreachable!());
+    /// ```
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        // Cycle every element through the front, pushing back the first of
+        // each run. Once the first element is pushed back, the back is always
+        // the last element kept.
+        for visited in 0..self.len {
+            // SAFETY: We unwrap here as the elements not visited yet are
+            // still in the buffer.
+            let mut value: T = self.pop_front().unwrap();
+            // SAFETY: We unwrap here as the first element was pushed back.
+            if visited > 0 && same_bucket(&mut value, self.back_mut().unwrap()) {
+                continue;
+            }
+            // Can't fail, the pop left room for it.
+            let _ = self.push_back(value);
+        }
+    }
+
+    /// Reverses the logical order of the elements in place.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    /// ring.reverse();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 3, 2, 1]);
+    ///
+    /// // The odd length leaves the middle element in place.
+    /// ring.pop_front().unwrap();
+    /// ring.reverse();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [1, 2, 3]);
+    ///
+    /// let mut single: RingBuffer<u8, 4> = RingBuffer::new();
+    /// single.push_front(7).unwrap();
+    /// single.reverse();
+    /// assert_eq!(single.iter().copied().collect::<Vec<u8>>(), [7]);
+    ///
+    /// let mut empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// empty.reverse();
+    /// assert!(empty.is_empty());
+    /// ```
+    pub fn reverse(&mut self) {
+        for index in 0..self.len / 2 {
+            self.swap_slots(index, self.len - 1 - index);
+        }
+        self.bump_generation();
+    }
+
+    /// Splits the ring buffer into a [`Producer`] that can only push to the
+    /// back and a [`Consumer`] that can only pop from the front, so each side
+    /// of a pipeline gets just the operations it needs. Both borrow the ring
+    /// buffer, which can't be used otherwise until they're dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{RingBuffer, ring_buffer::{Consumer, Producer}};
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// let (mut producer, mut consumer): (Producer<'_, u8, 4>, Consumer<'_, u8, 4>) = ring.split();
+    ///
+    /// for value in 0..4 {
+    ///     producer.push_back(value).unwrap();
+    /// }
+    /// assert!(producer.is_full());
+    /// assert!(producer.push_back(4).is_err());
+    ///
+    /// assert_eq!(consumer.pop_front().unwrap(), 0);
+    /// assert_eq!(consumer.pop_front().unwrap(), 1);
+    /// // Wrap the live elements around the end of the buffer.
+    /// producer.push_back(4).unwrap();
+    /// assert_eq!(consumer.len(), 3);
+    ///
+    /// let mut popped: Vec<u8> = Vec::new();
+    /// while let Ok(value) = consumer.pop_front() {
+    ///     popped.push(value);
+    /// }
+    /// assert_eq!(popped, [2, 3, 4]);
+    /// assert!(producer.is_empty());
+    ///
+    /// producer.push_back(5).unwrap();
+    /// drop((producer, consumer));
+    /// assert_eq!(ring.pop_front().unwrap(), 5);
+    /// ```
+    ///
+    /// The ring buffer stays borrowed while either half is alive:
+    /// ```compile_fail
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// let (mut producer, _consumer) = ring.split();
+    /// ring.push_back(0).unwrap();
+    /// producer.push_back(1).unwrap();
+    /// ```
+    pub fn split(&mut self) -> (Producer<'_, T, SIZE>, Consumer<'_, T, SIZE>) {
+        let ring: *mut Self = self;
+        (
+            Producer { ring, _marker: PhantomData },
+            Consumer { ring, _marker: PhantomData },
+        )
+    }
+
+    /// Creates a ring buffer holding `count` elements, the element at logical
+    /// index `i` being `f(i)`. `f` is called in order from `0` to `count - 1`.
+    ///
+    /// Returns `Err(FullCollectionError)` if `count > SIZE`, without calling
+    /// `f`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let squares: RingBuffer<usize, 8> = RingBuffer::from_fn(5, |index| index * index).unwrap();
+    /// assert_eq!(squares.len(), 5);
+    /// assert_eq!(squares.iter().copied().collect::<Vec<usize>>(), [0, 1, 4, 9, 16]);
+    ///
+    /// let empty: RingBuffer<usize, 8> = RingBuffer::from_fn(0, |_| unreachable!()).unwrap();
+    /// assert!(empty.is_empty());
+    ///
+    /// assert!(RingBuffer::<usize, 8>::from_fn(9, |_| unreachable!()).is_err());
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> T>(count: usize, mut f: F) -> Result<Self, FullCollectionError> {
+        if count > SIZE {
+            return Err(FullCollectionError);
+        }
+        let mut ring: Self = Self::new();
+        for index in 0..count {
+            // Can't fail, count <= SIZE.
+            let _ = ring.push_back(f(index));
+        }
+        Ok(ring)
+    }
+
+    /// Pushes an element to the back of the ring buffer, returning the new
+    /// length, or `None` if it's full, in which case `value` is dropped.
+    /// Use [`RingBuffer::push_back`] to get the value back in a
+    /// [`PushError`] instead, or [`RingBuffer::saturating_push_back`] if only
+    /// whether it was pushed matters.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 2> = RingBuffer::new();
+    /// assert_eq!(ring.try_push_back(1).map(|len| len.get()), Some(1));
+    /// assert_eq!(ring.try_push_back(2).map(|len| len.get()), Some(2));
+    /// assert_eq!(ring.try_push_back(3), None);
+    /// assert_eq!(ring.back(), Some(&2));
+    /// ```
+    pub fn try_push_back(&mut self, value: T) -> Option<NonZero<usize>> {
+        self.push_back(value).ok()
+    }
+
+    /// Pushes an element to the front of the ring buffer, returning the new
+    /// length, or `None` if it's full, in which case `value` is dropped.
+    /// Use [`RingBuffer::push_front`] to get the value back in a
+    /// [`PushError`] instead, or [`RingBuffer::saturating_push_front`] if only
+    /// whether it was pushed matters.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 2> = RingBuffer::new();
+    /// assert_eq!(ring.try_push_front(1).map(|len| len.get()), Some(1));
+    /// assert_eq!(ring.try_push_front(2).map(|len| len.get()), Some(2));
+    /// assert_eq!(ring.try_push_front(3), None);
+    /// assert_eq!(ring.front(), Some(&2));
+    /// ```
+    pub fn try_push_front(&mut self, value: T) -> Option<NonZero<usize>> {
+        self.push_front(value).ok()
+    }
+
+    /// Returns an iterator over the occupied slots in physical order, as
+    /// `(physical_index, &value)` pairs. Unlike [`RingBuffer::iter`], this
+    /// follows the layout of the underlying storage, which helps when
+    /// reasoning about wraparound.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 5> = RingBuffer::from([0, 1, 2, 3, 4]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(5).unwrap();
+    ///
+    /// let slots: Vec<(usize, &u8)> = ring.occupied_slots().collect();
+    /// assert_eq!(slots, [(0, &5), (3, &3), (4, &4)]);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [3, 4, 5]);
+    /// ```
+    pub fn occupied_slots(&self) -> impl Iterator<Item = (usize, &T)> {
+        let (front_slots, back_slots): (Range<usize>, Range<usize>) = self.slot_ranges();
+        let (front, back): (&[T], &[T]) = self.as_slices();
+        // The wrapped segment lies before head, so it comes first physically.
+        back_slots.zip(back).chain(front_slots.zip(front))
+    }
+
+    /// Returns a mutable reference to the last element, first pushing the
+    /// value returned by `f` if the ring buffer is empty. `f` isn't called
+    /// otherwise.
+    ///
+    /// Returns `Err(FullCollectionError)` if the ring buffer is empty but
+    /// can't hold the value, which only happens when `SIZE` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u32, 4> = RingBuffer::new();
+    /// *ring.get_or_insert_back_with(|| 10).unwrap() += 1;
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u32>>(), [11]);
+    ///
+    /// ring.push_front(0).unwrap();
+    /// *ring.get_or_insert_back_with(|| unreachable!()).unwrap() += 1;
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u32>>(), [0, 12]);
+    ///
+    /// let mut zero: RingBuffer<u32, 0> = RingBuffer::new();
+    /// assert!(zero.get_or_insert_back_with(|| 10).is_err());
+    /// ```
+    pub fn get_or_insert_back_with<F: FnOnce() -> T>(&mut self, f: F) -> Result<&mut T, FullCollectionError> {
+        if self.is_empty() {
+            self.push_back(f())?;
+        }
+        // SAFETY: We unwrap here as the ring buffer isn't empty.
+        Ok(self.back_mut().unwrap())
+    }
+
+    /// Creates a ring buffer holding the elements of `iter` in order.
+    ///
+    /// Returns `Err(FullCollectionError)` as soon as the iterator yields more
+    /// than `SIZE` elements, dropping the elements taken so far. Unlike
+    /// [`FromIterator`], which silently ignores the extra elements, this
+    /// requires every element to fit.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::try_from_iter(0..4).unwrap();
+    /// assert!(ring.is_full());
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3]);
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::try_from_iter(0..2).unwrap();
+    /// assert_eq!(ring.len(), 2);
+    ///
+    /// assert!(RingBuffer::<u8, 4>::try_from_iter(0..5).is_err());
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, FullCollectionError> {
+        let mut ring: Self = Self::new();
+        ring.try_extend(iter)?;
+        Ok(ring)
+    }
+
+    /// Returns references to the first and last elements, or `None` if the
+    /// ring buffer is empty. Both are the same element when `len == 1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.ends(), None);
+    ///
+    /// ring.push_back(1).unwrap();
+    /// assert_eq!(ring.ends(), Some((&1, &1)));
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_front(0).unwrap();
+    /// ring.push_back(2).unwrap();
+    /// assert_eq!(ring.ends(), Some((&0, &2)));
+    /// ```
+    pub fn ends(&self) -> Option<(&T, &T)> {
+        Some((self.front()?, self.back()?))
+    }
+
+    /// Returns mutable references to the first and last elements, or `None`
+    /// if `len < 2`, as a single element would be borrowed mutably twice.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(ring.ends_mut(), None);
+    ///
+    /// ring.push_back(1).unwrap();
+    /// assert_eq!(ring.ends_mut(), None);
+    ///
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.push_front(0).unwrap();
+    /// ring.push_back(2).unwrap();
+    /// let (front, back): (&mut u8, &mut u8) = ring.ends_mut().unwrap();
+    /// core::mem::swap(front, back);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [2, 1, 0]);
+    /// ```
+    pub fn ends_mut(&mut self) -> Option<(&mut T, &mut T)> {
+        let [front, back]: [&mut T; 2] = self.get_many_mut([0, self.len.checked_sub(1)?])?;
+        Some((front, back))
+    }
+
+    /// Returns an iterator that removes and yields the elements for which `f`
+    /// returns `true`, front to back. `f` is passed a mutable reference, so
+    /// it can also modify the elements it keeps.
+    ///
+    /// The remaining elements keep their logical order. Elements the
+    /// iterator hasn't reached when it's dropped stay in the ring buffer,
+    /// whether they match or not.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::from([0, 1, 2, 3, 4, 5]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(6).unwrap();
+    /// ring.push_back(7).unwrap();
+    ///
+    /// let odd: Vec<u8> = ring.extract_if(|value| *value % 2 == 1).collect();
+    /// assert_eq!(odd, [3, 5, 7]);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [2, 4, 6]);
+    /// ```
+    ///
+    /// Dropping the iterator early:
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::from([0, 1, 2, 3, 4, 5]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(6).unwrap();
+    ///
+    /// let mut extract = ring.extract_if(|value| *value % 2 == 0);
+    /// assert_eq!(extract.next(), Some(2));
+    /// drop(extract);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [1, 3, 4, 5, 6]);
+    /// ```
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> ExtractIf<'_, T, SIZE, F> {
+        let unvisited: usize = self.len;
+        ExtractIf { ring: self, f, unvisited }
+    }
+
+    /// Pushes an element to the back of the ring buffer if there's room.
+    /// Returns whether it was pushed; when the ring buffer is full, `value`
+    /// is dropped and `false` is returned.
+    /// Use [`RingBuffer::push_back`] to get the value back in a
+    /// [`PushError`] instead, or [`RingBuffer::try_push_back`] to get the
+    /// new length.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 2> = RingBuffer::new();
+    /// assert!(ring.saturating_push_back(1));
+    /// assert!(ring.saturating_push_back(2));
+    /// assert!(!ring.saturating_push_back(3));
+    /// assert_eq!(ring.len(), 2);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [1, 2]);
+    /// ```
+    pub fn saturating_push_back(&mut self, value: T) -> bool {
+        self.push_back(value).is_ok()
+    }
+
+    /// Pushes an element to the front of the ring buffer if there's room.
+    /// Returns whether it was pushed; when the ring buffer is full, `value`
+    /// is dropped and `false` is returned.
+    /// Use [`RingBuffer::push_front`] to get the value back in a
+    /// [`PushError`] instead, or [`RingBuffer::try_push_front`] to get the
+    /// new length.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 2> = RingBuffer::new();
+    /// assert!(ring.saturating_push_front(1));
+    /// assert!(ring.saturating_push_front(2));
+    /// assert!(!ring.saturating_push_front(3));
+    /// assert_eq!(ring.len(), 2);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [2, 1]);
+    /// ```
+    pub fn saturating_push_front(&mut self, value: T) -> bool {
+        self.push_front(value).is_ok()
+    }
+
+    /// Returns an iterator over the elements from logical index `start` to
+    /// the back. If `start >= len` the iterator is empty.
+    ///
+    /// Unlike `iter().skip(start)`, this is still a [`DoubleEndedIterator`]
+    /// and an [`ExactSizeIterator`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring.iter_from(0).copied().collect::<Vec<u8>>(), [2, 3, 4]);
+    /// assert_eq!(ring.iter_from(1).copied().collect::<Vec<u8>>(), [3, 4]);
+    /// assert_eq!(ring.iter_from(2).rev().copied().collect::<Vec<u8>>(), [4]);
+    /// assert_eq!(ring.iter_from(1).len(), 2);
+    /// assert_eq!(ring.iter_from(3).len(), 0);
+    /// assert_eq!(ring.iter_from(10).next(), None);
+    /// ```
+    pub fn iter_from(&self, start: usize) -> Iter<'_, T> {
+        let (front, back): (&[T], &[T]) = self.as_slices();
+        Iter::over_range(front, back, start.min(self.len)..self.len)
+    }
+
+    /// Rotates the logical order so the element at logical `index` becomes
+    /// the front, keeping the elements' cyclic order. This is
+    /// [`RingBuffer::rotate_left`]`(index)`, with the same cost: O(1) when
+    /// the ring buffer is full.
+    ///
+    /// # Panics
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// ring.rotate_to_front(2);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [3, 4, 1, 2]);
+    /// ring.rotate_to_front(0);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [3, 4, 1, 2]);
+    /// ring.rotate_to_front(3);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [2, 3, 4, 1]);
+    ///
+    /// // Not full.
+    /// ring.pop_back().unwrap();
+    /// ring.rotate_to_front(1);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [3, 4, 2]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.rotate_to_front(3);
+    /// ```
+    pub fn rotate_to_front(&mut self, index: usize) {
+        if index >= self.len {
+            panic!("index out of bounds: the len is {} but the index is {index}", self.len);
+        }
+        self.rotate_left(index);
+    }
+
+    /// Replaces the contents with copies of the elements of `slice`, in
+    /// order, starting at the first slot.
+    ///
+    /// # Panics
+    /// Panics if `slice.len() > SIZE`, leaving the ring buffer unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// // Wrap the live elements around the end of the buffer.
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// ring.copy_from_slice(&[7, 8, 9]);
+    /// assert_eq!(ring.len(), 3);
+    /// assert_eq!(ring.as_slices(), (&[7, 8, 9][..], &[][..]));
+    ///
+    /// ring.copy_from_slice(&[]);
+    /// assert!(ring.is_empty());
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 2> = RingBuffer::new();
+    /// ring.copy_from_slice(&[0, 1, 2]);
+    /// ```
+    pub fn copy_from_slice(&mut self, slice: &[T]) where T: Copy {
+        if slice.len() > SIZE {
+            panic!("source slice length ({}) exceeds the capacity ({SIZE})", slice.len());
+        }
+        self.clear();
+        // Can't fail, the slice fits in the empty ring buffer.
+        let _ = self.extend_from_slice(slice);
+    }
+
+    /// Returns an iterator removing and yielding up to `n` elements from the
+    /// front, stopping early if the ring buffer runs out.
+    ///
+    /// Unlike [`RingBuffer::drain`], elements are only removed as they're
+    /// yielded, so the ones not reached when the iterator is dropped stay in
+    /// the ring buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 6> = RingBuffer::from([0, 1, 2, 3, 4, 5]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(6).unwrap();
+    ///
+    /// assert_eq!(ring.take_front(2).collect::<Vec<u8>>(), [1, 2]);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [3, 4, 5, 6]);
+    ///
+    /// let mut take = ring.take_front(3);
+    /// assert_eq!(take.len(), 3);
+    /// assert_eq!(take.next(), Some(3));
+    /// drop(take);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [4, 5, 6]);
+    ///
+    /// assert_eq!(ring.take_front(3).collect::<Vec<u8>>(), [4, 5, 6]);
+    /// assert!(ring.is_empty());
+    ///
+    /// ring.extend([7, 8]);
+    /// let take = ring.take_front(5);
+    /// assert_eq!(take.len(), 2);
+    /// assert_eq!(take.collect::<Vec<u8>>(), [7, 8]);
+    /// assert!(ring.is_empty());
+    /// ```
+    pub fn take_front(&mut self, n: usize) -> TakeFront<'_, T, SIZE> {
+        TakeFront { ring: self, remaining: n }
+    }
+
+    /// Records that the stored elements or their layout changed.
+    /// Does nothing without the `generation` feature.
+    fn bump_generation(&mut self) {
+        #[cfg(feature = "generation")]
+        {
+            self.generation = self.generation.wrapping_add(1);
+        }
+    }
+
+    /// Swaps the elements at logical indices `i` and `j`, both `< len`. Unlike
+    /// [`RingBuffer::swap`], this neither checks the indices nor counts as a
+    /// modification of its own, for operations shifting several elements.
+    fn swap_slots(&mut self, i: usize, j: usize) {
+        let i: usize = self.physical_index(i);
+        let j: usize = self.physical_index(j);
+        self.buffer.swap(i, j);
+    }
+
+    /// Maps a logical index (0 being the front) to its physical slot.
+    /// `index` can be up to `SIZE`, which maps back to head.
+    fn physical_index(&self, index: usize) -> usize {
+        wrapping_offset(self.head, index, SIZE)
+    }
+
+    /// Returns the physical ranges of the occupied slots in logical order.
+    /// The second range is only non-empty when the elements wrap around the
+    /// end of the buffer.
+    fn slot_ranges(&self) -> (Range<usize>, Range<usize>) {
+        // Compared against the slots left after head, as head + len could
+        // overflow.
+        let until_end: usize = SIZE - self.head;
+        if self.len <= until_end {
+            (self.head..self.head + self.len, 0..0)
+        } else {
+            (self.head..SIZE, 0..self.len - until_end)
+        }
+    }
+
+    /// Returns an iterator that goes over the elements from front to back,
+    /// then starts again from the front, forever. It yields nothing if the
+    /// ring buffer is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let cycled: Vec<u8> = ring.into_cyclic_iter().take(7).copied().collect();
+    /// assert_eq!(cycled, [2, 3, 4, 2, 3, 4, 2]);
+    ///
+    /// let empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(empty.into_cyclic_iter().next(), None);
+    /// ```
+    pub fn into_cyclic_iter(&self) -> RingBufferCyclicIterator<'_, T, SIZE> {
+        RingBufferCyclicIterator { buffer: self, iter: self.iter() }
+    }
+}
+
+/// Returns the slot `offset` places after `head` in a buffer of `size` slots,
+/// wrapping around the end. Requires `head < size` and `offset <= size`.
+///
+/// Unlike `(head + offset) % size`, this can't overflow, which matters for
+/// zero-sized elements where `size` can be as large as `usize::MAX`.
+fn wrapping_offset(head: usize, offset: usize, size: usize) -> usize {
+    let until_end: usize = size - head;
+    if offset < until_end {
+        head + offset
+    } else {
+        offset - until_end
+    }
+}
+
+impl<T, const SIZE: usize> Default for RingBuffer<T, SIZE> {
+    /// Creates an empty RingBuffer, same as [`RingBuffer::new`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring = RingBuffer::<u8, 32>::default();
+    /// assert!(ring.is_empty());
+    /// assert_eq!(ring.capacity(), 32);
+    /// ```
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const SIZE: usize> Clone for RingBuffer<T, SIZE> where T: Clone {
+    /// Deep-clones the ring buffer, preserving its physical layout so the
+    /// clone behaves identically under subsequent pushes and pops.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let mut clone: RingBuffer<u8, 4> = ring.clone();
+    /// assert!(ring.iter().eq(clone.iter()));
+    ///
+    /// clone.pop_front().unwrap();
+    /// clone.push_back(5).unwrap();
+    /// ring[0] = 9;
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [9, 2, 3, 4]);
+    /// assert_eq!(clone.iter().copied().collect::<Vec<u8>>(), [2, 3, 4, 5]);
+    /// ```
+    fn clone(&self) -> Self {
+        let mut ring: Self = Self::new();
+        // Pushing from the same head reproduces the physical layout.
+        ring.head = self.head;
+        for value in self.iter() {
+            // Can't fail, both ring buffers have the same capacity.
+            let _ = ring.push_back(value.clone());
+        }
+        ring
+    }
+
+    /// Drops the current elements, then clones those of `source` into the
+    /// existing storage with the same physical layout.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// let mut source: RingBuffer<u8, 4> = RingBuffer::new();
+    /// source.push_back(10).unwrap();
+    /// source.push_front(11).unwrap();
+    ///
+    /// ring.clone_from(&source);
+    /// assert_eq!(ring, [11, 10]);
+    /// ```
+    fn clone_from(&mut self, source: &Self) {
+        self.clear();
+        self.head = source.head;
+        for value in source.iter() {
+            // Can't fail, both ring buffers have the same capacity.
+            let _ = self.push_back(value.clone());
+        }
+    }
+}
+
+impl<T, const SIZE: usize, const OTHER_SIZE: usize> PartialEq<RingBuffer<T, OTHER_SIZE>> for RingBuffer<T, SIZE>
+    where T: PartialEq {
+    /// Compares the elements in logical order, ignoring the physical layout
+    /// and capacity of either ring buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let other: RingBuffer<u8, 4> = RingBuffer::from([1, 2, 3, 4]);
+    /// assert_eq!(ring, other);
+    ///
+    /// let mut larger: RingBuffer<u8, 8> = RingBuffer::new();
+    /// for value in 1..5 {
+    ///     larger.push_back(value).unwrap();
+    /// }
+    /// assert_eq!(ring, larger);
+    ///
+    /// larger.pop_back().unwrap();
+    /// assert_ne!(ring, larger);
+    ///
+    /// let different: RingBuffer<u8, 4> = RingBuffer::from([1, 2, 3, 5]);
+    /// assert_ne!(ring, different);
+    /// ```
+    fn eq(&self, other: &RingBuffer<T, OTHER_SIZE>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T, const SIZE: usize, const N: usize> PartialEq<[T; N]> for RingBuffer<T, SIZE> where T: PartialEq {
+    /// Compares the elements in logical order with those of an array.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring, [1, 2, 3, 4]);
+    /// assert_ne!(ring, [1, 2, 3]);
+    /// assert_ne!(ring, [1, 2, 3, 5]);
+    /// ```
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T, const SIZE: usize> PartialEq<[T]> for RingBuffer<T, SIZE> where T: PartialEq {
+    /// Compares the elements in logical order with those of a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert!(ring == [1, 2, 3, 4][..]);
+    /// assert!(ring != [1, 2][..]);
+    /// ```
+    fn eq(&self, other: &[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other)
+    }
+}
+
+impl<T, const SIZE: usize> PartialEq<&[T]> for RingBuffer<T, SIZE> where T: PartialEq {
+    /// Compares the elements in logical order with those of a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let values: &[u8] = &[1, 2, 3, 4];
+    /// assert_eq!(ring, values);
+    /// assert_ne!(ring, &values[1..]);
+    /// ```
+    fn eq(&self, other: &&[T]) -> bool {
+        *self == **other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const SIZE: usize> PartialEq<Vec<T>> for RingBuffer<T, SIZE> where T: PartialEq {
+    /// Compares the elements in logical order with those of a `Vec`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring, vec![1, 2, 3, 4]);
+    /// assert_ne!(ring, vec![1, 2, 3, 4, 5]);
+    /// assert_ne!(ring, Vec::new());
+    /// ```
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T, const SIZE: usize> Eq for RingBuffer<T, SIZE> where T: Eq {}
+
+impl<T, const SIZE: usize, const OTHER_SIZE: usize> PartialOrd<RingBuffer<T, OTHER_SIZE>> for RingBuffer<T, SIZE>
+    where T: PartialOrd {
+    /// Compares the elements lexicographically in logical order, like slices
+    /// do, ignoring the physical layout and capacity of either ring buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let mut prefix: RingBuffer<u8, 8> = RingBuffer::new();
+    /// prefix.push_back(1).unwrap();
+    /// prefix.push_back(2).unwrap();
+    /// assert!(prefix < ring);
+    ///
+    /// prefix.push_back(4).unwrap();
+    /// assert!(prefix > ring);
+    ///
+    /// let equal: RingBuffer<f32, 4> = RingBuffer::from([1.0, 2.0, 3.0, 4.0]);
+    /// let mut other: RingBuffer<f32, 4> = RingBuffer::from([0.0, 1.0, 2.0, 3.0]);
+    /// other.pop_front().unwrap();
+    /// other.push_back(4.0).unwrap();
+    /// assert_eq!(equal.partial_cmp(&other), Some(core::cmp::Ordering::Equal));
+    /// ```
+    fn partial_cmp(&self, other: &RingBuffer<T, OTHER_SIZE>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T, const SIZE: usize> Ord for RingBuffer<T, SIZE> where T: Ord {
+    /// Compares the elements lexicographically in logical order, like slices
+    /// do, ignoring the physical layout of either ring buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut wrapped: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// wrapped.pop_front().unwrap();
+    /// wrapped.push_back(4).unwrap();
+    ///
+    /// let mut rings: Vec<RingBuffer<u8, 4>> = vec![
+    ///     RingBuffer::from([1, 2, 3, 5]),
+    ///     wrapped,
+    ///     RingBuffer::from_iter([1, 2]),
+    ///     RingBuffer::from([0, 9, 9, 9]),
+    /// ];
+    /// rings.sort();
+    ///
+    /// let sorted: Vec<Vec<u8>> = rings.iter()
+    ///     .map(|ring| ring.iter().copied().collect())
+    ///     .collect();
+    /// assert_eq!(sorted, [vec![0, 9, 9, 9], vec![1, 2], vec![1, 2, 3, 4], vec![1, 2, 3, 5]]);
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T, const SIZE: usize> Hash for RingBuffer<T, SIZE> where T: Hash {
+    /// Hashes the length and then the elements in logical order, so ring
+    /// buffers that compare equal hash the same whatever their physical layout.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashSet;
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    /// let other: RingBuffer<u8, 4> = RingBuffer::from([1, 2, 3, 4]);
+    ///
+    /// let mut set: HashSet<RingBuffer<u8, 4>> = HashSet::new();
+    /// assert!(set.insert(ring));
+    /// assert!(!set.insert(other));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T, const SIZE: usize> Debug for RingBuffer<T, SIZE> where T: Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RingBuffer")
+            .field("head", &self.head)
             .field("len", &self.len)
-            .field("buffer", &self.buffer)
+            .field("buffer", &PhysicalSlots(self))
+            .finish()
+    }
+}
+
+/// Formats every physical slot of a ring buffer, `None` being an empty slot.
+struct PhysicalSlots<'a, T, const SIZE: usize>(&'a RingBuffer<T, SIZE>);
+
+impl<T, const SIZE: usize> Debug for PhysicalSlots<'_, T, SIZE> where T: Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let ring: &RingBuffer<T, SIZE> = self.0;
+        f.debug_list()
+            .entries((0..SIZE).map(|index| {
+                // Logical position of the slot, relative to head.
+                let offset: usize = if index >= ring.head {
+                    index - ring.head
+                } else {
+                    index + (SIZE - ring.head)
+                };
+                ring.get(offset)
+            }))
             .finish()
     }
 }
 
-// Note: This prevents double Option wraps.
-impl<T, const SIZE: usize> From<[Option<T>; SIZE]> for RingBuffer<T, SIZE> {
-    fn from(buffer: [Option<T>; SIZE]) -> Self {
-        Self { 
-            head: 0, 
-            len: SIZE, 
-            buffer 
+impl<T, const SIZE: usize> Display for RingBuffer<T, SIZE> where T: Display {
+    /// Formats the elements in logical order, e.g. `[1, 2, 3]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    /// assert_eq!(ring.to_string(), "[1, 2, 3, 4]");
+    ///
+    /// let empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(empty.to_string(), "[]");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+// Note: This prevents double Option wraps. `None` slots are skipped, the
+// remaining elements are packed at the front in order.
+impl<T, const SIZE: usize> From<[Option<T>; SIZE]> for RingBuffer<T, SIZE> {
+    fn from(buffer: [Option<T>; SIZE]) -> Self {
+        buffer.into_iter().flatten().collect()
+    }
+}
+
+impl<T, const SIZE: usize> From<[T; SIZE]> for RingBuffer<T, SIZE> {
+    fn from(buffer: [T; SIZE]) -> Self {
+        Self {
+            head: 0,
+            len: SIZE,
+            buffer: buffer.map(MaybeUninit::new),
+            #[cfg(feature = "generation")]
+            generation: 0,
+        }
+    }
+}
+
+impl<T, const SIZE: usize> TryFrom<&[T]> for RingBuffer<T, SIZE> where T: Clone {
+    type Error = FullCollectionError;
+
+    /// Clones the elements of `slice` into a new ring buffer, front to back.
+    /// Returns `Err(FullCollectionError)` if the slice is longer than `SIZE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::try_from(&[0, 1, 2][..]).unwrap();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2]);
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::try_from(&[0, 1, 2, 3][..]).unwrap();
+    /// assert!(ring.is_full());
+    ///
+    /// assert!(RingBuffer::<u8, 4>::try_from(&[0, 1, 2, 3, 4][..]).is_err());
+    /// ```
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        if slice.len() > SIZE {
+            return Err(FullCollectionError);
+        }
+        Ok(slice.iter().cloned().collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const SIZE: usize> TryFrom<VecDeque<T>> for RingBuffer<T, SIZE> {
+    type Error = FullCollectionError;
+
+    /// Moves the elements of `deque` into a new ring buffer, front to back.
+    /// Returns `Err(FullCollectionError)` if the deque is longer than `SIZE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut deque: VecDeque<u8> = VecDeque::from([1, 2]);
+    /// deque.push_front(0);
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::try_from(deque).unwrap();
+    /// assert_eq!(ring, [0, 1, 2]);
+    ///
+    /// let deque: VecDeque<u8> = ring.into_vecdeque();
+    /// assert_eq!(deque, [0, 1, 2]);
+    ///
+    /// assert!(RingBuffer::<u8, 2>::try_from(deque).is_err());
+    /// ```
+    fn try_from(deque: VecDeque<T>) -> Result<Self, Self::Error> {
+        if deque.len() > SIZE {
+            return Err(FullCollectionError);
+        }
+        Ok(deque.into_iter().collect())
+    }
+}
+
+impl<T, const SIZE: usize> FromIterator<T> for RingBuffer<T, SIZE> {
+    /// Collects up to `SIZE` elements into a ring buffer, front to back.
+    /// Once the ring buffer is full the rest of the iterator is ignored and
+    /// left unconsumed.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring: RingBuffer<u8, 4> = (0..2).collect();
+    /// assert_eq!(ring.len(), 2);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1]);
+    ///
+    /// let ring: RingBuffer<u8, 4> = (0..4).collect();
+    /// assert!(ring.is_full());
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3]);
+    ///
+    /// let mut iter = 0..10;
+    /// let ring: RingBuffer<u8, 4> = iter.by_ref().collect();
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3]);
+    /// assert_eq!(iter.next(), Some(4));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ring: Self = Self::new();
+        for value in iter.into_iter().take(SIZE) {
+            // Can't fail, take stops at SIZE elements.
+            let _ = ring.push_back(value);
+        }
+        ring
+    }
+}
+
+impl<T, const SIZE: usize> Extend<T> for RingBuffer<T, SIZE> {
+    /// Pushes the elements of `iter` to the back in order. Once the ring
+    /// buffer is full the rest of the iterator is ignored and left
+    /// unconsumed; use [`RingBuffer::try_extend`] to detect this.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring.extend([0, 1]);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1]);
+    ///
+    /// ring.extend(2..10);
+    /// assert!(ring.is_full());
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3]);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let remaining: usize = self.remaining_capacity();
+        for value in iter.into_iter().take(remaining) {
+            // Can't fail, take stops at the remaining capacity.
+            let _ = self.push_back(value);
+        }
+    }
+}
+
+impl<'a, T, const SIZE: usize> Extend<&'a T> for RingBuffer<T, SIZE> where T: Copy + 'a {
+    /// Pushes copies of the elements of `iter` to the back in order. Once the
+    /// ring buffer is full the rest of the iterator is ignored and left
+    /// unconsumed.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring.extend(&[0, 1]);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1]);
+    ///
+    /// let values: [u8; 4] = [2, 3, 4, 5];
+    /// let mut iter = values.iter();
+    /// ring.extend(&mut iter);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [0, 1, 2, 3]);
+    /// assert_eq!(iter.as_slice(), [4, 5]);
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, const SIZE: usize> Index<usize> for RingBuffer<T, SIZE> {
+    type Output = T;
+
+    /// Returns a reference to the element at logical `index`, 0 being the
+    /// front.
+    ///
+    /// # Panics
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// assert_eq!(ring[0], 1);
+    /// assert_eq!(ring[3], 4);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// let _ = ring[0];
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    /// let _ = ring[3];
+    /// ```
+    fn index(&self, index: usize) -> &Self::Output {
+        let len: usize = self.len;
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<T, const SIZE: usize> IndexMut<usize> for RingBuffer<T, SIZE> {
+    /// Returns a mutable reference to the element at logical `index`, 0 being
+    /// the front.
+    ///
+    /// # Panics
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// ring[3] = 9;
+    /// ring[0] += 1;
+    /// assert_eq!(ring.iter().copied().collect::<Vec<u8>>(), [2, 2, 3, 9]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring[0] = 1;
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len: usize = self.len;
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<T, const SIZE: usize> Drop for RingBuffer<T, SIZE> {
+    /// Drops the live elements, from front to back. Slots that were never
+    /// filled, or whose element was already removed, aren't touched.
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const SIZE: usize> IntoIterator for RingBuffer<T, SIZE> {
+    type Item = T;
+    type IntoIter = IntoIter<T, SIZE>;
+
+    /// Consumes the ring buffer into an iterator yielding its elements from
+    /// front to back.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let mut values: Vec<u8> = Vec::new();
+    /// for value in ring {
+    ///     values.push(value);
+    /// }
+    /// assert_eq!(values, [1, 2, 3, 4]);
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// assert_eq!(ring.into_iter().rev().collect::<Vec<u8>>(), [3, 2, 1, 0]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { buffer: self }
+    }
+}
+
+/// Endlessly repeating iterator over the elements of a [`RingBuffer`], from
+/// front to back.
+///
+/// Created by [`RingBuffer::into_cyclic_iter`].
+pub struct RingBufferCyclicIterator<'a, T, const SIZE: usize> {
+    buffer: &'a RingBuffer<T, SIZE>,
+    // The rest of the current pass over the elements.
+    iter: Iter<'a, T>,
+}
+
+impl<'a, T, const SIZE: usize> Iterator for RingBufferCyclicIterator<'a, T, SIZE> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().or_else(|| {
+            self.iter = self.buffer.iter();
+            self.iter.next()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.buffer.is_empty() {
+            (0, Some(0))
+        } else {
+            (usize::MAX, None)
+        }
+    }
+}
+
+/// Borrowing iterator over the elements of a [`RingBuffer`], from front to
+/// back.
+///
+/// Created by [`RingBuffer::iter`].
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Iterates over the logical `range` of the elements split across
+    /// `front` and `back`, as returned by [`RingBuffer::as_slices`].
+    fn over_range(front: &'a [T], back: &'a [T], range: Range<usize>) -> Self {
+        let split: usize = front.len();
+        Self {
+            front: front[range.start.min(split)..range.end.min(split)].iter(),
+            back: back[range.start.saturating_sub(split)..range.end.saturating_sub(split)].iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    /// Clones the iterator, so the remaining elements can be iterated over
+    /// again. Unlike a derived impl, this doesn't require `T: Clone`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{RingBuffer, ring_buffer::Iter};
+    ///
+    /// // Not Clone, but references to it can still be iterated twice.
+    /// #[derive(PartialEq, Debug)]
+    /// struct Sample(i32);
+    ///
+    /// let mut ring: RingBuffer<Sample, 4> = RingBuffer::new();
+    /// ring.push_back(Sample(2)).unwrap();
+    /// ring.push_back(Sample(3)).unwrap();
+    /// ring.push_front(Sample(1)).unwrap();
+    ///
+    /// let iter: Iter<'_, Sample> = ring.iter();
+    /// let first: Vec<&Sample> = iter.clone().collect();
+    /// let second: Vec<&Sample> = iter.collect();
+    /// assert_eq!(first, second);
+    /// assert_eq!(first, [&Sample(1), &Sample(2), &Sample(3)]);
+    /// ```
+    ///
+    /// Summing the elements of a wrapped ring buffer:
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<i32, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(-4).unwrap();
+    ///
+    /// let iter = ring.iter();
+    /// assert_eq!(iter.clone().copied().sum::<i32>(), 2);
+    /// assert_eq!(iter.copied().product::<i32>(), -24);
+    /// ```
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+/// Mutably borrowing iterator over the elements of a [`RingBuffer`], from
+/// front to back.
+///
+/// Created by [`RingBuffer::iter_mut`].
+pub struct IterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Owning iterator over the elements of a [`RingBuffer`], from front to back.
+///
+/// Created by [`RingBuffer::into_iter`]. Elements that haven't been yielded
+/// are dropped together with the iterator.
+pub struct IntoIter<T, const SIZE: usize> {
+    buffer: RingBuffer<T, SIZE>,
+}
+
+impl<T, const SIZE: usize> Iterator for IntoIter<T, SIZE> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.pop_front().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buffer.len(), Some(self.buffer.len()))
+    }
+}
+
+impl<T, const SIZE: usize> DoubleEndedIterator for IntoIter<T, SIZE> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.buffer.pop_back().ok()
+    }
+}
+
+impl<T, const SIZE: usize> ExactSizeIterator for IntoIter<T, SIZE> {}
+
+/// Draining iterator over the elements of a [`RingBuffer`], from front to
+/// back.
+///
+/// Created by [`RingBuffer::drain`]. Elements that haven't been yielded are
+/// dropped together with the iterator.
+pub struct Drain<'a, T> {
+    buffer: &'a mut [MaybeUninit<T>],
+    head: usize,
+    len: usize,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: The len slots from head hold the elements not yielded yet.
+        let value: T = unsafe { self.buffer[self.head].assume_init_read() };
+        self.head = wrapping_offset(self.head, 1, self.buffer.len());
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
         }
+        let index: usize = wrapping_offset(self.head, self.len - 1, self.buffer.len());
+        // SAFETY: The len slots from head hold the elements not yielded yet.
+        let value: T = unsafe { self.buffer[index].assume_init_read() };
+        self.len -= 1;
+        Some(value)
     }
 }
 
-impl<T, const SIZE: usize> From<[T; SIZE]> for RingBuffer<T, SIZE> {
-    fn from(buffer: [T; SIZE]) -> Self {
-        Self {
-            head: 0,
-            len: SIZE,
-            buffer: buffer.map(|val| { Some(val) }),
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// Iterator over overlapping windows of a [`RingBuffer`]'s elements.
+///
+/// Created by [`RingBuffer::windows`].
+pub struct Windows<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+    size: usize,
+    start: usize,
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end: usize = self.start + self.size;
+        if end > self.front.len() + self.back.len() {
+            return None;
         }
+        let window: Iter<'a, T> = Iter::over_range(self.front, self.back, self.start..end);
+        self.start += 1;
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = (self.front.len() + self.back.len() + 1).saturating_sub(self.start + self.size);
+        (len, Some(len))
     }
 }
 
-impl<T, const SIZE: usize> Index<usize> for RingBuffer<T, SIZE> {
-    type Output = T;
+impl<T> ExactSizeIterator for Windows<'_, T> {}
 
-    fn index(&self, index: usize) -> &Self::Output {
-        if index >= self.len() {
-            panic!("Index out of bounds");
+/// Iterator over non-overlapping chunks of a [`RingBuffer`]'s elements.
+///
+/// Created by [`RingBuffer::chunks`].
+pub struct Chunks<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+    size: usize,
+    start: usize,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len: usize = self.front.len() + self.back.len();
+        if self.start >= len {
+            return None;
         }
-        let actual_index: usize = (self.head + index) % SIZE;
-        // SAFETY: We unwrap here as this value shouldn't be None
-        self.buffer[actual_index].as_ref().unwrap()
+        let end: usize = len.min(self.start + self.size);
+        let chunk: Iter<'a, T> = Iter::over_range(self.front, self.back, self.start..end);
+        self.start = end;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = (self.front.len() + self.back.len() - self.start).div_ceil(self.size);
+        (len, Some(len))
     }
 }
 
-impl<T, const SIZE: usize> IndexMut<usize> for RingBuffer<T, SIZE> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        if index >= self.len() {
-            panic!("Index out of bounds");
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+/// Iterator over non-overlapping chunks of exactly the same size of a
+/// [`RingBuffer`]'s elements.
+///
+/// Created by [`RingBuffer::chunks_exact`].
+pub struct ChunksExact<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+    size: usize,
+    start: usize,
+    // End of the last whole chunk.
+    end: usize,
+}
+
+impl<'a, T> ChunksExact<'a, T> {
+    /// Returns an iterator over the elements left over after the last whole
+    /// chunk, of which there are fewer than the chunk size.
+    pub fn remainder(&self) -> Iter<'a, T> {
+        let len: usize = self.front.len() + self.back.len();
+        Iter::over_range(self.front, self.back, self.end..len)
+    }
+}
+
+impl<'a, T> Iterator for ChunksExact<'a, T> {
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start >= self.end {
+            return None;
         }
-        let actual_index: usize = (self.head + index) % SIZE;
-        self.buffer[actual_index].as_mut().unwrap()
+        let end: usize = self.start + self.size;
+        let chunk: Iter<'a, T> = Iter::over_range(self.front, self.back, self.start..end);
+        self.start = end;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = (self.end - self.start) / self.size;
+        (len, Some(len))
     }
 }
 
-pub struct RingBufferCyclicIterator<'a, T, const SIZE: usize>{
-    buffer: &'a RingBuffer<T, SIZE>,
-    index: usize
+impl<T> ExactSizeIterator for ChunksExact<'_, T> {}
+
+/// Handle that can only push to the back of a [`RingBuffer`].
+///
+/// Created by [`RingBuffer::split`], along with its [`Consumer`].
+pub struct Producer<'a, T, const SIZE: usize> {
+    ring: *mut RingBuffer<T, SIZE>,
+    _marker: PhantomData<&'a mut RingBuffer<T, SIZE>>,
 }
 
-impl<'a, T, const SIZE: usize> Iterator for RingBufferCyclicIterator<'a, T, SIZE> {
-    type Item = &'a T;
+impl<T, const SIZE: usize> Producer<'_, T, SIZE> {
+    /// Pushes an element to the back of the ring buffer.
+    /// Returns `Err(PushError(value))` if the ring buffer is full, handing
+    /// the value back.
+    /// Returns the new length otherwise.
+    pub fn push_back(&mut self, value: T) -> Result<NonZero<usize>, PushError<T>> {
+        // SAFETY: The ring buffer is borrowed mutably for 'a, and only this
+        // handle and its consumer reach it. Neither is Send nor hands out
+        // references into it, so no other reference is live during the call.
+        unsafe { (*self.ring).push_back(value) }
+    }
+
+    /// Returns number of elements in the ring buffer.
+    pub fn len(&self) -> usize {
+        // SAFETY: As in push_back.
+        unsafe { (*self.ring).len() }
+    }
+
+    /// Returns `true` if the ring buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the ring buffer is full.
+    pub fn is_full(&self) -> bool {
+        self.len() == SIZE
+    }
+}
+
+/// Handle that can only pop from the front of a [`RingBuffer`].
+///
+/// Created by [`RingBuffer::split`], along with its [`Producer`].
+pub struct Consumer<'a, T, const SIZE: usize> {
+    ring: *mut RingBuffer<T, SIZE>,
+    _marker: PhantomData<&'a mut RingBuffer<T, SIZE>>,
+}
+
+impl<T, const SIZE: usize> Consumer<'_, T, SIZE> {
+    /// Removes first element and returns it.
+    /// If the ring buffer is empty, we return Err(EmptyCollectionError)
+    pub fn pop_front(&mut self) -> Result<T, EmptyCollectionError> {
+        // SAFETY: The ring buffer is borrowed mutably for 'a, and only this
+        // handle and its producer reach it. Neither is Send nor hands out
+        // references into it, so no other reference is live during the call.
+        unsafe { (*self.ring).pop_front() }
+    }
+
+    /// Returns number of elements in the ring buffer.
+    pub fn len(&self) -> usize {
+        // SAFETY: As in pop_front.
+        unsafe { (*self.ring).len() }
+    }
+
+    /// Returns `true` if the ring buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Iterator removing the elements of a [`RingBuffer`] that match a predicate.
+///
+/// Created by [`RingBuffer::extract_if`].
+pub struct ExtractIf<'a, T, const SIZE: usize, F> where F: FnMut(&mut T) -> bool {
+    ring: &'a mut RingBuffer<T, SIZE>,
+    f: F,
+    // Number of elements not visited yet. They sit at the front of the ring
+    // buffer, followed by the visited ones that were kept.
+    unvisited: usize,
+}
+
+impl<T, const SIZE: usize, F> Iterator for ExtractIf<'_, T, SIZE, F> where F: FnMut(&mut T) -> bool {
+    type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.buffer.is_empty() {
+        while self.unvisited > 0 {
+            self.unvisited -= 1;
+            // SAFETY: We unwrap here as the elements not visited yet are
+            // still in the buffer.
+            let mut value: T = self.ring.pop_front().unwrap();
+            if (self.f)(&mut value) {
+                return Some(value);
+            }
+            // Can't fail, the pop left room for it.
+            let _ = self.ring.push_back(value);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.unvisited))
+    }
+}
+
+impl<T, const SIZE: usize, F> Drop for ExtractIf<'_, T, SIZE, F> where F: FnMut(&mut T) -> bool {
+    fn drop(&mut self) {
+        // Move the elements not visited back after the kept ones.
+        self.ring.rotate_left(self.unvisited);
+    }
+}
+
+/// Iterator removing up to a given number of elements from the front of a
+/// [`RingBuffer`].
+///
+/// Created by [`RingBuffer::take_front`].
+pub struct TakeFront<'a, T, const SIZE: usize> {
+    ring: &'a mut RingBuffer<T, SIZE>,
+    remaining: usize,
+}
+
+impl<T, const SIZE: usize> Iterator for TakeFront<'_, T, SIZE> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
             return None;
         }
-        self.index = (self.index + 1) % SIZE;
-        let val = Some(&self.buffer[self.index]);
-        self.index += 1;
-        val
+        let value: T = self.ring.pop_front().ok()?;
+        self.remaining -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.remaining.min(self.ring.len());
+        (len, Some(len))
+    }
+}
+
+impl<T, const SIZE: usize> ExactSizeIterator for TakeFront<'_, T, SIZE> {}
+
+/// Read-only view of a [`RingBuffer`]'s elements, from front to back.
+///
+/// Created by [`RingBuffer::view`].
+pub struct RingView<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+}
+
+impl<'a, T> RingView<'a, T> {
+    /// Returns number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Returns `true` if the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element at logical `index`, or `None` if
+    /// it's out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let view = ring.view();
+    /// assert_eq!(view.get(3), Some(&4));
+    /// assert_eq!(view.get(4), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        match index.checked_sub(self.front.len()) {
+            None => self.front.get(index),
+            Some(index) => self.back.get(index),
+        }
+    }
+
+    /// Returns an iterator over the elements, from front to back.
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            front: self.front.iter(),
+            back: self.back.iter(),
+        }
+    }
+}
+
+impl<T> Clone for RingView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RingView<'_, T> {}
+
+impl<T> Debug for RingView<'_, T> where T: Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Index<usize> for RingView<'_, T> {
+    type Output = T;
+
+    /// Returns a reference to the element at logical `index`, 0 being the
+    /// front.
+    ///
+    /// # Panics
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    /// ```should_panic
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// let view = ring.view();
+    /// let _ = view[4];
+    /// ```
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the len is {} but the index is {index}", self.len()),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for RingView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &RingView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use std::{format, vec::Vec};
+
+    use super::*;
+
+    /// Bumps a shared counter when dropped, so tests can check that every
+    /// element is dropped exactly once. The id tells elements apart.
+    #[derive(Clone)]
+    struct DropCounter<'a> {
+        drops: &'a Cell<usize>,
+        id: u8,
+    }
+
+    impl<'a> DropCounter<'a> {
+        fn new(drops: &'a Cell<usize>, id: u8) -> Self {
+            Self { drops, id }
+        }
+    }
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    /// Returns the ids of the elements, from front to back.
+    fn ids<const SIZE: usize>(ring: &RingBuffer<DropCounter<'_>, SIZE>) -> Vec<u8> {
+        ring.iter().map(|value| value.id).collect()
+    }
+
+    #[test]
+    fn every_element_is_dropped_exactly_once() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 0..4 {
+            assert!(ring.push_back(DropCounter::new(&drops, id)).is_ok());
+        }
+        // Overwrite the front twice, wrapping the live elements around the end.
+        drop(ring.force_push_back(DropCounter::new(&drops, 4)));
+        drop(ring.force_push_back(DropCounter::new(&drops, 5)));
+        assert_eq!(drops.get(), 2);
+
+        drop(ring.pop_back().unwrap());
+        assert_eq!(drops.get(), 3);
+        ring.clear();
+        assert_eq!(drops.get(), 6);
+
+        // Dropping a partially filled, wrapped ring buffer drops only what's left.
+        for id in 0..3 {
+            assert!(ring.push_front(DropCounter::new(&drops, id)).is_ok());
+        }
+        drop(ring);
+        assert_eq!(drops.get(), 9);
+    }
+
+    #[test]
+    fn drop_touches_only_live_elements() {
+        // One counter per element, to check none is dropped twice.
+        let drops: [Cell<usize>; 6] = Default::default();
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for (id, counter) in (0..).zip(&drops[..4]) {
+            assert!(ring.push_back(DropCounter::new(counter, id)).is_ok());
+        }
+        let popped: DropCounter<'_> = ring.pop_front().unwrap();
+        drop(ring.pop_front().unwrap());
+        // Wrap the live elements around the end of the buffer.
+        for (id, counter) in (4..).zip(&drops[4..]) {
+            assert!(ring.push_back(DropCounter::new(counter, id)).is_ok());
+        }
+
+        drop(ring);
+        assert_eq!(drops[0].get(), 0);
+        assert!(drops[1..].iter().all(|counter| counter.get() == 1));
+
+        drop(popped);
+        assert_eq!(drops[0].get(), 1);
+    }
+
+    #[test]
+    fn clear_drops_wrapped_elements() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 0..4 {
+            assert!(ring.push_back(DropCounter::new(&drops, id)).is_ok());
+        }
+        // Move the head so the live elements wrap around the end.
+        drop(ring.pop_front().unwrap());
+        drop(ring.pop_front().unwrap());
+        assert!(ring.push_back(DropCounter::new(&drops, 4)).is_ok());
+        assert_eq!(drops.get(), 2);
+
+        ring.clear();
+        assert!(ring.is_empty());
+        assert_eq!(drops.get(), 5);
+
+        assert!(ring.push_back(DropCounter::new(&drops, 5)).is_ok());
+        assert_eq!(ring.len(), 1);
+        drop(ring);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn reset_from_drops_old_elements_and_restarts_at_first_slot() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 0..3 {
+            assert!(ring.push_front(DropCounter::new(&drops, id)).is_ok());
+        }
+
+        let new: [DropCounter<'_>; 4] = core::array::from_fn(|id| DropCounter::new(&drops, 10 + id as u8));
+        assert_eq!(ring.reset_from(new).unwrap(), 4);
+        assert_eq!(drops.get(), 3);
+        assert_eq!(ids(&ring), [10, 11, 12, 13]);
+        // A full ring buffer starting at the first slot is contiguous.
+        assert!(ring.try_as_slice().is_some());
+
+        drop(ring);
+        assert_eq!(drops.get(), 7);
+    }
+
+    #[test]
+    fn reset_from_keeps_what_fits_on_overflow() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 2> = RingBuffer::new();
+        assert!(ring.push_back(DropCounter::new(&drops, 0)).is_ok());
+
+        let new: [DropCounter<'_>; 3] = core::array::from_fn(|id| DropCounter::new(&drops, 10 + id as u8));
+        assert!(ring.reset_from(new).is_err());
+        // The old element and the one that didn't fit.
+        assert_eq!(drops.get(), 2);
+        assert_eq!(ids(&ring), [10, 11]);
+    }
+
+    #[test]
+    fn truncate_drops_from_the_back() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 1..4 {
+            assert!(ring.push_back(DropCounter::new(&drops, id)).is_ok());
+        }
+        // Wrap the live elements around the end of the buffer.
+        assert!(ring.push_front(DropCounter::new(&drops, 0)).is_ok());
+
+        ring.truncate(5);
+        assert_eq!(ring.len(), 4);
+        assert_eq!(drops.get(), 0);
+
+        ring.truncate(1);
+        assert_eq!(drops.get(), 3);
+        assert_eq!(ids(&ring), [0]);
+
+        drop(ring);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn resize_with_drops_the_excess_when_shrinking() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        let mut next: u8 = 0;
+        ring.resize_with(4, || {
+            next += 1;
+            DropCounter::new(&drops, next)
+        }).unwrap();
+        assert_eq!(drops.get(), 0);
+
+        assert!(ring.resize_with(5, || unreachable!()).is_err());
+        assert_eq!(ring.len(), 4);
+
+        ring.resize_with(1, || unreachable!()).unwrap();
+        assert_eq!(drops.get(), 3);
+        assert_eq!(ids(&ring), [1]);
+    }
+
+    #[test]
+    fn retain_drops_rejected_elements() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 8> = RingBuffer::new();
+        for id in 3..8 {
+            assert!(ring.push_back(DropCounter::new(&drops, id)).is_ok());
+        }
+        // Wrap the live elements around the end of the buffer.
+        for id in (0..3).rev() {
+            assert!(ring.push_front(DropCounter::new(&drops, id)).is_ok());
+        }
+
+        ring.retain(|value| value.id % 3 != 0);
+        assert_eq!(drops.get(), 3);
+        assert_eq!(ids(&ring), [1, 2, 4, 5, 7]);
+
+        ring.retain(|_| true);
+        assert_eq!(drops.get(), 3);
+        assert_eq!(ring.len(), 5);
+
+        ring.retain(|_| false);
+        assert_eq!(drops.get(), 8);
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn retain_mut_drops_rejected_elements() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 5> = RingBuffer::new();
+        for id in 1..5 {
+            assert!(ring.push_back(DropCounter::new(&drops, id)).is_ok());
+        }
+        // Wrap the live elements around the end of the buffer.
+        assert!(ring.push_front(DropCounter::new(&drops, 0)).is_ok());
+
+        ring.retain_mut(|value| {
+            value.id *= 10;
+            value.id % 20 == 0
+        });
+        assert_eq!(drops.get(), 2);
+        assert_eq!(ids(&ring), [0, 20, 40]);
+    }
+
+    #[test]
+    fn dedup_by_drops_each_removed_element_once() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in [1, 2, 2] {
+            assert!(ring.push_back(DropCounter::new(&drops, id)).is_ok());
+        }
+        // A run at the front, across the wrap.
+        assert!(ring.push_front(DropCounter::new(&drops, 1)).is_ok());
+
+        ring.dedup_by(|a, b| a.id == b.id);
+        assert_eq!(drops.get(), 2);
+        assert_eq!(ids(&ring), [1, 2]);
+    }
+
+    #[test]
+    fn drain_drops_unyielded_elements() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 0..4 {
+            assert!(ring.push_back(DropCounter::new(&drops, id)).is_ok());
+        }
+
+        let mut drain: Drain<'_, DropCounter<'_>> = ring.drain();
+        assert_eq!(drain.len(), 4);
+        drop(drain.next());
+        drop(drain);
+        assert_eq!(drops.get(), 4);
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn leaked_drain_leaves_the_ring_buffer_empty() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 0..4 {
+            assert!(ring.push_back(DropCounter::new(&drops, id)).is_ok());
+        }
+
+        mem::forget(ring.drain());
+        // The elements are leaked along with the iterator, never dropped.
+        assert!(ring.is_empty());
+        assert!(ring.push_back(DropCounter::new(&drops, 4)).is_ok());
+        drop(ring);
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn into_iter_drops_unyielded_elements() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 0..4 {
+            assert!(ring.push_back(DropCounter::new(&drops, id)).is_ok());
+        }
+
+        let mut iter: IntoIter<DropCounter<'_>, 4> = ring.into_iter();
+        drop(iter.next());
+        assert_eq!(drops.get(), 1);
+        assert_eq!(iter.len(), 3);
+
+        drop(iter);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn resize_to_drops_everything_when_too_small() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 0..3 {
+            assert!(ring.push_front(DropCounter::new(&drops, id)).is_ok());
+        }
+
+        assert!(ring.resize_to::<2>().is_err());
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn try_into_array_moves_without_dropping() {
+        let drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 2> = RingBuffer::new();
+        assert!(ring.push_front(DropCounter::new(&drops, 1)).is_ok());
+        assert!(ring.push_front(DropCounter::new(&drops, 0)).is_ok());
+
+        let array: [DropCounter<'_>; 2] = ring.try_into_array().ok().unwrap();
+        assert_eq!(drops.get(), 0);
+        assert_eq!(array.each_ref().map(|value| value.id), [0, 1]);
+        drop(array);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn clone_from_drops_the_old_elements() {
+        let old_drops: Cell<usize> = Cell::new(0);
+        let new_drops: Cell<usize> = Cell::new(0);
+        let mut ring: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 0..3 {
+            assert!(ring.push_back(DropCounter::new(&old_drops, id)).is_ok());
+        }
+        let mut source: RingBuffer<DropCounter<'_>, 4> = RingBuffer::new();
+        for id in 10..12 {
+            assert!(source.push_front(DropCounter::new(&new_drops, id)).is_ok());
+        }
+
+        ring.clone_from(&source);
+        assert_eq!(old_drops.get(), 3);
+        assert_eq!(new_drops.get(), 0);
+        assert_eq!(ids(&ring), [11, 10]);
+
+        drop(ring);
+        assert_eq!(new_drops.get(), 2);
+    }
+
+    #[test]
+    fn zero_capacity_is_always_empty_and_full() {
+        let mut ring: RingBuffer<u8, 0> = RingBuffer::new();
+        assert!(ring.is_empty() && ring.is_full());
+        assert_eq!((ring.len(), ring.capacity(), ring.remaining_capacity()), (0, 0, 0));
+
+        assert_eq!(ring.push_back(1), Err(PushError(1)));
+        assert_eq!(ring.push_front(2), Err(PushError(2)));
+        assert_eq!(ring.force_push_back(3), Some(3));
+        assert_eq!(ring.force_push_front(4), Some(4));
+        assert!(ring.insert(0, 5).is_err());
+        assert!(ring.extend_from_slice(&[6]).is_err());
+        ring.fill(7);
+        ring.extend([8]);
+
+        assert!(ring.pop_front().is_err() && ring.pop_back().is_err());
+        assert!(ring.front().is_none() && ring.back().is_none() && ring.get(0).is_none());
+        assert!(ring.remove(0).is_none());
+        assert_eq!(ring.iter().count(), 0);
+        assert_eq!(ring.drain().count(), 0);
+        assert_eq!(ring.windows(1).count(), 0);
+        assert!(ring.make_contiguous().is_empty());
+        ring.rotate_left(1);
+        ring.rotate_right(1);
+        ring.reverse();
+        ring.retain(|_| true);
+        ring.truncate(0);
+        ring.clear();
+        assert_eq!(format!("{ring:?}"), "RingBuffer { head: 0, len: 0, buffer: [] }");
+
+        let mut smaller: RingBuffer<u8, 0> = [1, 2].into_iter().collect();
+        assert!(smaller.is_empty());
+        smaller.clone_from(&ring);
+        assert_eq!(smaller.resize_to::<2>().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn index_arithmetic_never_overflows() {
+        // Zero-sized elements allow a capacity of usize::MAX.
+        #[derive(PartialEq, Debug)]
+        struct Tick;
+
+        let mut ring: RingBuffer<Tick, { usize::MAX }> = RingBuffer::new();
+        // Move the head to the very last slot, so the back wraps past usize::MAX.
+        ring.push_front(Tick).unwrap();
+        ring.push_back(Tick).unwrap();
+        ring.push_back(Tick).unwrap();
+        assert_eq!(ring.len(), 3);
+        assert_eq!(ring.get(2), Some(&Tick));
+        assert_eq!(ring.iter().count(), 3);
+        let (front, back): (&[Tick], &[Tick]) = ring.as_slices();
+        assert_eq!((front.len(), back.len()), (1, 2));
+
+        ring.rotate_left(1);
+        assert_eq!(ring.pop_back().unwrap(), Tick);
+        assert_eq!(ring.pop_front().unwrap(), Tick);
+        assert_eq!(ring.drain().rev().count(), 1);
+        assert!(ring.is_empty());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn cyclic_iter_stays_within_len() {
+        // Used to skip elements and index past len.
+        let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+        ring.push_back(0).unwrap();
+        ring.push_back(1).unwrap();
+        let cycled: Vec<u8> = ring.into_cyclic_iter().take(5).copied().collect();
+        assert_eq!(cycled, [0, 1, 0, 1, 0]);
+    }
+
+    #[cfg(feature = "generation")]
+    #[test]
+    fn generation_costs_one_u64() {
+        assert_eq!(size_of::<RingBuffer<u8, 64>>(), 64 + 2 * size_of::<usize>() + size_of::<u64>());
+    }
+
+    #[cfg(feature = "generation")]
+    #[test]
+    fn shifting_operations_bump_generation_once() {
+        let mut ring: RingBuffer<u8, 8> = RingBuffer::from([0, 1, 2, 3, 4, 5, 6, 7]);
+        ring.truncate(6);
+
+        let start: u64 = ring.generation();
+        ring.insert(3, 9).unwrap();
+        assert_eq!(ring.generation(), start + 1);
+        assert_eq!(ring.remove(2), Some(2));
+        assert_eq!(ring.generation(), start + 2);
+        ring.reverse();
+        assert_eq!(ring.generation(), start + 3);
+        assert_eq!(ring.swap_remove_back(1), Some(4));
+        assert_eq!(ring.generation(), start + 4);
+        assert_eq!(ring.swap_remove_front(1), Some(0));
+        assert_eq!(ring.generation(), start + 5);
+        assert_eq!(ring, [5, 3, 9, 1]);
+    }
+}
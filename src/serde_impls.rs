@@ -0,0 +1,80 @@
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::RingBuffer;
+
+impl<T, const SIZE: usize> Serialize for RingBuffer<T, SIZE> where T: Serialize {
+    /// Serializes the elements as a sequence in logical front-to-back order.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    /// assert_eq!(serde_json::to_string(&ring).unwrap(), "[1,2,3,4]");
+    ///
+    /// let empty: RingBuffer<u8, 4> = RingBuffer::new();
+    /// assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+    /// ```
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T, const SIZE: usize> Deserialize<'de> for RingBuffer<T, SIZE> where T: Deserialize<'de> {
+    /// Deserializes a sequence of at most `SIZE` elements, pushing them to the
+    /// back in order. Longer sequences are rejected.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::from([0, 1, 2, 3]);
+    /// ring.pop_front().unwrap();
+    /// ring.pop_front().unwrap();
+    /// ring.push_back(4).unwrap();
+    ///
+    /// let json: String = serde_json::to_string(&ring).unwrap();
+    /// let round_trip: RingBuffer<u8, 4> = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(round_trip, ring);
+    /// assert_eq!(round_trip.len(), 3);
+    ///
+    /// let empty: RingBuffer<u8, 4> = serde_json::from_str("[]").unwrap();
+    /// assert!(empty.is_empty());
+    ///
+    /// let full: RingBuffer<u8, 4> = serde_json::from_str("[1,2,3,4]").unwrap();
+    /// assert!(full.is_full());
+    ///
+    /// assert!(serde_json::from_str::<RingBuffer<u8, 4>>("[1,2,3,4,5]").is_err());
+    /// ```
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_seq(RingBufferVisitor(PhantomData))
+    }
+}
+
+struct RingBufferVisitor<T, const SIZE: usize>(PhantomData<T>);
+
+impl<'de, T, const SIZE: usize> Visitor<'de> for RingBufferVisitor<T, SIZE> where T: Deserialize<'de> {
+    type Value = RingBuffer<T, SIZE>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of at most {SIZE} elements")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: SeqAccess<'de> {
+        let mut ring: RingBuffer<T, SIZE> = RingBuffer::new();
+        while let Some(value) = seq.next_element()? {
+            if ring.push_back(value).is_err() {
+                // Saturating, as zero-sized elements allow SIZE == usize::MAX.
+                return Err(A::Error::invalid_length(SIZE.saturating_add(1), &self));
+            }
+        }
+        Ok(ring)
+    }
+}
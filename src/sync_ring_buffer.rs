@@ -0,0 +1,222 @@
+use core::num::NonZero;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::{error::{EmptyCollectionError, PushError}, ring_buffer::RingBuffer};
+
+/// A [`RingBuffer`] behind a [`Mutex`], so it can be shared between threads
+/// and used through `&self`.
+///
+/// It's `Send` and `Sync` whenever `T` is `Send`.
+///
+/// # Examples
+/// ```
+/// use std::{sync::Arc, thread};
+/// use fixed_collections::SyncRingBuffer;
+///
+/// let ring: Arc<SyncRingBuffer<u32, 4>> = Arc::new(SyncRingBuffer::new());
+/// let producer: thread::JoinHandle<()> = {
+///     let ring: Arc<SyncRingBuffer<u32, 4>> = Arc::clone(&ring);
+///     thread::spawn(move || {
+///         ring.push_back(1).unwrap();
+///         ring.push_back(2).unwrap();
+///     })
+/// };
+/// producer.join().unwrap();
+///
+/// assert_eq!(ring.pop_front().unwrap(), 1);
+/// assert_eq!(ring.pop_front().unwrap(), 2);
+/// assert!(ring.is_empty());
+/// ```
+pub struct SyncRingBuffer<T, const SIZE: usize> {
+    ring: Mutex<RingBuffer<T, SIZE>>,
+}
+
+impl<T, const SIZE: usize> SyncRingBuffer<T, SIZE> {
+    /// Creates an empty ring buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::SyncRingBuffer;
+    ///
+    /// let ring: SyncRingBuffer<u32, 16> = SyncRingBuffer::new();
+    /// assert!(ring.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { ring: Mutex::new(RingBuffer::new()) }
+    }
+
+    /// Returns number of elements in the ring buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::SyncRingBuffer;
+    ///
+    /// let ring: SyncRingBuffer<u8, 4> = SyncRingBuffer::new();
+    /// ring.push_back(1).unwrap();
+    /// ring.push_back(2).unwrap();
+    /// assert_eq!(ring.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if the ring buffer is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::SyncRingBuffer;
+    ///
+    /// let ring: SyncRingBuffer<u8, 4> = SyncRingBuffer::new();
+    /// assert!(ring.is_empty());
+    ///
+    /// ring.push_back(1).unwrap();
+    /// assert!(!ring.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Pushes an element to the back of the ring buffer.
+    /// Returns `Err(PushError(value))` if the ring buffer is full, handing
+    /// the value back.
+    /// Returns the new length otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{SyncRingBuffer, error::PushError};
+    ///
+    /// let ring: SyncRingBuffer<u8, 2> = SyncRingBuffer::new();
+    /// assert_eq!(ring.push_back(1).unwrap().get(), 1);
+    /// assert_eq!(ring.push_back(2).unwrap().get(), 2);
+    /// assert_eq!(ring.push_back(3), Err(PushError(3)));
+    /// ```
+    pub fn push_back(&self, value: T) -> Result<NonZero<usize>, PushError<T>> {
+        self.lock().push_back(value)
+    }
+
+    /// Pushes an element to the back of the ring buffer, overwriting the
+    /// front element if it's full.
+    /// Returns the overwritten element, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::SyncRingBuffer;
+    ///
+    /// let ring: SyncRingBuffer<u8, 2> = SyncRingBuffer::new();
+    /// assert_eq!(ring.force_push_back(1), None);
+    /// assert_eq!(ring.force_push_back(2), None);
+    /// assert_eq!(ring.force_push_back(3), Some(1));
+    /// assert_eq!(ring.pop_front().unwrap(), 2);
+    /// ```
+    pub fn force_push_back(&self, value: T) -> Option<T> {
+        self.lock().force_push_back(value)
+    }
+
+    /// Removes first element and returns it.
+    /// If the ring buffer is empty, we return Err(EmptyCollectionError)
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::SyncRingBuffer;
+    ///
+    /// let ring: SyncRingBuffer<u8, 4> = SyncRingBuffer::new();
+    /// assert!(ring.pop_front().is_err());
+    ///
+    /// ring.push_back(1).unwrap();
+    /// ring.push_back(2).unwrap();
+    /// assert_eq!(ring.pop_front().unwrap(), 1);
+    /// assert_eq!(ring.pop_front().unwrap(), 2);
+    /// ```
+    pub fn pop_front(&self) -> Result<T, EmptyCollectionError> {
+        self.lock().pop_front()
+    }
+
+    /// Consumes the wrapper, returning the inner ring buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_collections::{RingBuffer, SyncRingBuffer};
+    ///
+    /// let ring: SyncRingBuffer<u8, 4> = SyncRingBuffer::new();
+    /// ring.push_back(1).unwrap();
+    ///
+    /// let inner: RingBuffer<u8, 4> = ring.into_inner();
+    /// assert_eq!(inner.front(), Some(&1));
+    /// ```
+    pub fn into_inner(self) -> RingBuffer<T, SIZE> {
+        self.ring.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks the ring buffer. A panic while it was locked can't leave it in
+    /// an inconsistent state, so poisoning is ignored.
+    fn lock(&self) -> MutexGuard<'_, RingBuffer<T, SIZE>> {
+        self.ring.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T, const SIZE: usize> Default for SyncRingBuffer<T, SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const SIZE: usize> From<RingBuffer<T, SIZE>> for SyncRingBuffer<T, SIZE> {
+    fn from(ring: RingBuffer<T, SIZE>) -> Self {
+        Self { ring: Mutex::new(ring) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread, vec::Vec};
+
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn is_send_and_sync_when_elements_are_send() {
+        assert_send_sync::<SyncRingBuffer<u32, 4>>();
+        // `Cell` is `Send` but not `Sync`, the mutex makes up for it.
+        assert_send_sync::<SyncRingBuffer<core::cell::Cell<u32>, 4>>();
+    }
+
+    #[test]
+    fn concurrent_producers_deliver_every_value_once() {
+        const PRODUCERS: u32 = 4;
+        const PER_PRODUCER: u32 = 250;
+
+        let ring: Arc<SyncRingBuffer<u32, 16>> = Arc::new(SyncRingBuffer::new());
+        let producers: Vec<thread::JoinHandle<()>> = (0..PRODUCERS)
+            .map(|producer| {
+                let ring: Arc<SyncRingBuffer<u32, 16>> = Arc::clone(&ring);
+                thread::spawn(move || {
+                    for value in 0..PER_PRODUCER {
+                        let mut value: u32 = producer * PER_PRODUCER + value;
+                        // Retry until the consumer makes room.
+                        while let Err(error) = ring.push_back(value) {
+                            value = error.into_inner();
+                            thread::yield_now();
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        let mut seen: Vec<u32> = Vec::new();
+        while seen.len() < (PRODUCERS * PER_PRODUCER) as usize {
+            match ring.pop_front() {
+                Ok(value) => seen.push(value),
+                Err(_) => thread::yield_now(),
+            }
+        }
+        for producer in producers {
+            producer.join().unwrap();
+        }
+
+        seen.sort();
+        assert!(seen.iter().copied().eq(0..PRODUCERS * PER_PRODUCER));
+        assert!(ring.is_empty());
+    }
+}